@@ -0,0 +1,157 @@
+use crate::{
+    staking::{Multiplier, BASE_MULTIPLIER, MAX_MULTIPLIER},
+    week_timekeeping::Epoch,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_ACTIVE_BOOSTS: usize = 10;
+
+/// A price of 0 disables paying with that token
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct BoostConfig<M: ManagedTypeApi> {
+    pub multiplier_bonus: Multiplier,
+    pub duration_epochs: Epoch,
+    pub gas_price: BigUint<M>,
+    pub egld_price: BigUint<M>,
+    pub max_active_boosts: usize,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug,
+)]
+pub struct ActiveBoost {
+    pub multiplier_bonus: Multiplier,
+    pub end_epoch: Epoch,
+}
+
+#[multiversx_sc::module]
+pub trait BoostModule:
+    crate::rewards::RewardsModule
+    + crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::storage_version::StorageVersionModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Changes only apply to boosts bought afterwards
+    #[only_owner]
+    #[endpoint(setBoostConfig)]
+    fn set_boost_config(&self, config: BoostConfig<Self::Api>) {
+        require!(
+            config.multiplier_bonus > 0 && config.multiplier_bonus <= MAX_MULTIPLIER,
+            "Invalid multiplier"
+        );
+        require!(config.duration_epochs > 0, "Invalid duration");
+        require!(
+            config.max_active_boosts > 0 && config.max_active_boosts <= MAX_ACTIVE_BOOSTS,
+            "Invalid max active boosts"
+        );
+        require!(
+            config.gas_price > 0 || config.egld_price > 0,
+            "Boost must have a price"
+        );
+
+        self.boost_config().set(config);
+    }
+
+    /// Pay the exact price, either in GAS, which is burned,
+    /// or in EGLD, which is split with the next collected dev rewards
+    /// Active boosts stack, up to the configured maximum per user
+    #[payable("*")]
+    #[endpoint]
+    fn boost(&self) {
+        self.require_not_paused();
+
+        let config_mapper = self.boost_config();
+        require!(!config_mapper.is_empty(), "Boosts not configured");
+
+        let config = config_mapper.get();
+        let payment = self.call_value().egld_or_single_esdt();
+        if payment.token_identifier.is_egld() {
+            require!(
+                config.egld_price > 0 && payment.amount == config.egld_price,
+                "Invalid payment amount"
+            );
+
+            self.pending_boost_rewards()
+                .update(|pending_rewards| *pending_rewards += &payment.amount);
+        } else {
+            self.token()
+                .require_same_token(&payment.token_identifier.unwrap_esdt());
+            require!(
+                config.gas_price > 0 && payment.amount == config.gas_price,
+                "Invalid payment amount"
+            );
+
+            self.token().burn(&payment.amount);
+        }
+
+        let caller = self.blockchain().get_caller();
+        let user_id = self.user_id().get_id_or_insert(&caller);
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut active_boosts = self.get_active_boosts_for_user_id(user_id);
+        require!(
+            active_boosts.len() < config.max_active_boosts,
+            "Too many active boosts"
+        );
+
+        active_boosts.push(ActiveBoost {
+            multiplier_bonus: config.multiplier_bonus,
+            end_epoch: current_epoch + config.duration_epochs,
+        });
+        self.user_boosts(user_id).set(active_boosts);
+    }
+
+    #[view(getActiveBoosts)]
+    fn get_active_boosts(&self, user: ManagedAddress) -> ManagedVec<ActiveBoost> {
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 {
+            return ManagedVec::new();
+        }
+
+        self.get_active_boosts_for_user_id(user_id)
+    }
+
+    /// Stacked bonuses are capped at MAX_MULTIPLIER
+    #[view(getBoostMultiplier)]
+    fn get_boost_multiplier(&self, user: ManagedAddress) -> Multiplier {
+        let mut bonus = 0;
+        for boost in self.get_active_boosts(user).iter() {
+            bonus += boost.multiplier_bonus;
+        }
+
+        BASE_MULTIPLIER + core::cmp::min(bonus, MAX_MULTIPLIER)
+    }
+
+    fn get_active_boosts_for_user_id(&self, user_id: AddressId) -> ManagedVec<ActiveBoost> {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut active_boosts = ManagedVec::new();
+        for boost in self.user_boosts(user_id).get().iter() {
+            if boost.end_epoch > current_epoch {
+                active_boosts.push(boost);
+            }
+        }
+
+        active_boosts
+    }
+
+    #[view(getBoostConfig)]
+    #[storage_mapper("boostConfig")]
+    fn boost_config(&self) -> SingleValueMapper<BoostConfig<Self::Api>>;
+
+    #[storage_mapper("userBoosts")]
+    fn user_boosts(&self, user_id: AddressId) -> SingleValueMapper<ManagedVec<ActiveBoost>>;
+}
@@ -0,0 +1,263 @@
+use crate::{
+    leaderboard::LeaderboardKind,
+    pause_flags::PauseFlag,
+    staking_pool::MAX_PERCENTAGE,
+    week_timekeeping::{Epoch, Week},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type GuildId = AddressId;
+
+pub const TOP_GUILDS_FOR_PRIZES: usize = 3;
+pub const MAX_GUILD_NAME_LEN: usize = 32;
+
+/// prize_pool_percentage is the share of the weekly dev rewards, out of MAX_PERCENTAGE,
+/// split between the top guilds' members
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GuildConfig {
+    pub max_members: usize,
+    pub switch_cooldown_epochs: Epoch,
+    pub prize_pool_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GuildInfo<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub founder: ManagedAddress<M>,
+}
+
+/// Members' work adds up in the weekly guild leaderboard
+/// A user's work for a week counts for the guild they were in at their first work of the week
+#[multiversx_sc::module]
+pub trait GuildModule:
+    crate::leaderboard::LeaderboardModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// A lower member cap does not remove members from existing guilds
+    /// The prize pool and the staking pool may not take more than the whole dev rewards
+    #[only_owner]
+    #[endpoint(setGuildConfig)]
+    fn set_guild_config(&self, config: GuildConfig) {
+        require!(config.max_members > 0, "Invalid max members");
+        require!(
+            config.prize_pool_percentage <= MAX_PERCENTAGE - self.staking_pool_percentage().get(),
+            "Invalid percentage"
+        );
+
+        self.guild_config().set(config);
+    }
+
+    /// The caller becomes the guild's first member
+    #[endpoint(createGuild)]
+    fn create_guild(&self, name: ManagedBuffer) -> GuildId {
+        require!(
+            !name.is_empty() && name.len() <= MAX_GUILD_NAME_LEN,
+            "Invalid guild name"
+        );
+
+        let guild_id = self.last_guild_id().update(|last_guild_id| {
+            *last_guild_id += 1;
+            *last_guild_id
+        });
+        let caller = self.blockchain().get_caller();
+        self.guild_info(guild_id).set(GuildInfo {
+            name,
+            founder: caller.clone(),
+        });
+        self.join_guild_common(&caller, guild_id);
+
+        guild_id
+    }
+
+    #[endpoint(joinGuild)]
+    fn join_guild(&self, guild_id: GuildId) {
+        require!(!self.guild_info(guild_id).is_empty(), "Unknown guild");
+
+        let caller = self.blockchain().get_caller();
+        self.join_guild_common(&caller, guild_id);
+    }
+
+    /// The user may only join another guild once the switch cooldown passes
+    #[endpoint(leaveGuild)]
+    fn leave_guild(&self) {
+        let caller = self.blockchain().get_caller();
+        let user_id = self.user_id().get_id_non_zero(&caller);
+        let guild_id = self.user_guild(user_id).take();
+        require!(guild_id != 0, "Not in a guild");
+
+        let _ = self.guild_members(guild_id).swap_remove(&user_id);
+        let switch_cooldown_epochs = self.guild_config().get().switch_cooldown_epochs;
+        self.guild_rejoin_epoch(user_id)
+            .set(self.blockchain().get_block_epoch() + switch_cooldown_epochs);
+    }
+
+    /// A guild id of 0 means the user is not in a guild
+    #[view(getUserGuild)]
+    fn get_user_guild(&self, user: ManagedAddress) -> GuildId {
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 {
+            return 0;
+        }
+
+        self.user_guild(user_id).get()
+    }
+
+    #[view(getGuildMembers)]
+    fn get_guild_members(&self, guild_id: GuildId) -> MultiValueEncoded<ManagedAddress> {
+        let mut members = MultiValueEncoded::new();
+        for member_id in self.guild_members(guild_id).iter() {
+            if let Some(member) = self.user_id().get_address(member_id) {
+                members.push(member);
+            }
+        }
+
+        members
+    }
+
+    fn join_guild_common(&self, user: &ManagedAddress, guild_id: GuildId) {
+        self.require_flag_not_paused(PauseFlag::Registration);
+
+        let config_mapper = self.guild_config();
+        require!(!config_mapper.is_empty(), "Guilds not enabled");
+
+        let user_id = self.user_id().get_id_or_insert(user);
+        let user_guild_mapper = self.user_guild(user_id);
+        require!(user_guild_mapper.get() == 0, "Already in a guild");
+
+        require!(
+            self.blockchain().get_block_epoch() >= self.guild_rejoin_epoch(user_id).get(),
+            "Guild switch cooldown not over"
+        );
+
+        let mut members_mapper = self.guild_members(guild_id);
+        require!(
+            members_mapper.len() < config_mapper.get().max_members,
+            "Guild is full"
+        );
+
+        let _ = members_mapper.insert(user_id);
+        user_guild_mapper.set(guild_id);
+    }
+
+    fn increase_guild_entry(&self, user_id: AddressId, gas_amount: u64, score_increase: &BigUint) {
+        let current_week = self.get_current_week();
+        let guild_for_week_mapper = self.user_guild_for_week(user_id, current_week);
+        if guild_for_week_mapper.is_empty() {
+            let guild_id = self.user_guild(user_id).get();
+            if guild_id == 0 {
+                return;
+            }
+
+            guild_for_week_mapper.set(guild_id);
+        }
+
+        let guild_id = guild_for_week_mapper.get();
+        self.guild_member_score(user_id, current_week)
+            .update(|member_score| *member_score += score_increase);
+
+        let mut guild_entry =
+            self.get_leaderboard_entry_or_default(LeaderboardKind::Guilds, guild_id, current_week);
+        guild_entry.raw_gas += gas_amount;
+        guild_entry.score += score_increase;
+        self.save_leaderboard_entry(LeaderboardKind::Guilds, guild_entry, current_week);
+    }
+
+    /// Returns the share taken by the guild prize pool,
+    /// nothing is taken if no guild worked during the week
+    /// The share is capped at what the staking pool left, in case the staking pool was raised since
+    fn distribute_guild_prize_pool_share(
+        &self,
+        week: Week,
+        rewards: &BigUint,
+        staking_pool_share: &BigUint,
+    ) -> BigUint {
+        let config_mapper = self.guild_config();
+        if config_mapper.is_empty() || self.guild_leaderboard(week).is_empty() {
+            return BigUint::zero();
+        }
+
+        let pool_share = core::cmp::min(
+            rewards * config_mapper.get().prize_pool_percentage / MAX_PERCENTAGE,
+            rewards - staking_pool_share,
+        );
+        self.guild_rewards_week(week)
+            .update(|guild_rewards| *guild_rewards += &pool_share);
+
+        pool_share
+    }
+
+    fn prune_guild_member_entry(&self, user_id: AddressId, week: Week) {
+        self.user_guild_for_week(user_id, week).clear();
+        self.guild_member_score(user_id, week).clear();
+        self.user_claimed_guild_rewards_for_week(user_id, week)
+            .clear();
+    }
+
+    fn calculate_top_total_guild_score_for_week(&self, week: Week) -> BigUint {
+        let guild_leaderboard_mapper = self.guild_leaderboard(week);
+        let last_entry_index =
+            core::cmp::min(guild_leaderboard_mapper.len(), TOP_GUILDS_FOR_PRIZES);
+        let mut total_score = BigUint::zero();
+        for i in 1..=last_entry_index {
+            total_score += guild_leaderboard_mapper.get_unchecked(i).score;
+        }
+
+        total_score
+    }
+
+    #[view(getGuildConfig)]
+    #[storage_mapper("guildConfig")]
+    fn guild_config(&self) -> SingleValueMapper<GuildConfig>;
+
+    #[storage_mapper("lastGuildId")]
+    fn last_guild_id(&self) -> SingleValueMapper<GuildId>;
+
+    #[view(getGuildInfo)]
+    #[storage_mapper("guildInfo")]
+    fn guild_info(&self, guild_id: GuildId) -> SingleValueMapper<GuildInfo<Self::Api>>;
+
+    #[storage_mapper("guildMembers")]
+    fn guild_members(&self, guild_id: GuildId) -> UnorderedSetMapper<AddressId>;
+
+    #[storage_mapper("userGuild")]
+    fn user_guild(&self, user_id: AddressId) -> SingleValueMapper<GuildId>;
+
+    /// Set when leaving a guild, with the switch cooldown in effect at the time
+    #[storage_mapper("guildRejoinEpoch")]
+    fn guild_rejoin_epoch(&self, user_id: AddressId) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("userGuildForWeek")]
+    fn user_guild_for_week(&self, user_id: AddressId, week: Week) -> SingleValueMapper<GuildId>;
+
+    /// The member's contribution to their guild's score for the week
+    #[storage_mapper("guildMemberScore")]
+    fn guild_member_score(&self, user_id: AddressId, week: Week) -> SingleValueMapper<BigUint>;
+
+    /// Guild prize pool of the week
+    #[view(getGuildRewardsForWeek)]
+    #[storage_mapper("guildRewardsWeek")]
+    fn guild_rewards_week(&self, week: Week) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("guildRewardsPaidForWeek")]
+    fn guild_rewards_paid_for_week(&self, week: Week) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userClaimedGuildRewardsForWeek")]
+    fn user_claimed_guild_rewards_for_week(
+        &self,
+        user_id: AddressId,
+        week: Week,
+    ) -> SingleValueMapper<bool>;
+}
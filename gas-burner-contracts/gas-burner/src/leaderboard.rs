@@ -1,17 +1,47 @@
-use crate::week_timekeeping::Week;
+use crate::{
+    guild::GuildId,
+    league::Division,
+    staking::{Multiplier, BASE_MULTIPLIER},
+    week_timekeeping::Week,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug,
+)]
+/// The leaderboard is sorted by score
+/// Entries stored before storage version 3 had a single work amount, used for both fields
+/// In the guild leaderboard, user_id is the guild's id
 pub struct LeaderboardEntry<M: ManagedTypeApi> {
     pub user_id: AddressId,
-    pub work_amount: BigUint<M>,
+    pub raw_gas: BigUint<M>,
+    pub score: BigUint<M>,
+}
+
+/// All leaderboards share the ranking logic, with their own storage
+/// Division leaderboards hold the same user entries as the global one, split by division
+#[derive(Clone, Copy, PartialEq)]
+pub enum LeaderboardKind {
+    Users,
+    Guilds,
+    Division(Division),
 }
 
 #[multiversx_sc::module]
 pub trait LeaderboardModule:
-    crate::week_timekeeping::WeekTimekeepingModule + crate::signature::SignatureModule
+    crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::token::TokenModule
+    + multiversx_sc_modules::pause::PauseModule
 {
     /// A placement of 0 means the user does not exist for the given week
     #[view(getUserLeaderboardPlacement)]
@@ -24,27 +54,99 @@ pub trait LeaderboardModule:
         self.user_index_in_leaderboard(user_id, week).get()
     }
 
-    fn increase_leaderboard_entry(&self, user: &ManagedAddress, amount: u64) {
+    /// The score is computed with the week's curve on the user's total raw gas,
+    /// and the increase is scaled by the user's staking, boost and streak multipliers
+    /// Returns the score increase
+    fn increase_leaderboard_entry(
+        &self,
+        user: &ManagedAddress,
+        gas_amount: u64,
+        boost_multiplier: Multiplier,
+    ) -> BigUint {
         let current_week = self.get_current_week();
         let user_id = self.user_id().get_id_or_insert(user);
-        let user_index = self.user_index_in_leaderboard(user_id, current_week).get();
-        if user_index != 0 {
-            self.increase_work_amount(user_index, amount, current_week);
+        let multiplier = self.get_work_multiplier(user.clone()) * boost_multiplier
+            / BASE_MULTIPLIER
+            * self.get_streak_multiplier_for_week(user_id, current_week)
+            / BASE_MULTIPLIER;
+        let curve = self.get_or_snapshot_scoring_curve(current_week);
+
+        let mut user_entry =
+            self.get_leaderboard_entry_or_default(LeaderboardKind::Users, user_id, current_week);
+        let prev_curve_score = self.compute_score(&curve, &user_entry.raw_gas);
+        user_entry.raw_gas += gas_amount;
+        let curve_score_increase =
+            self.compute_score(&curve, &user_entry.raw_gas) - prev_curve_score;
+        let score_increase = curve_score_increase * multiplier / BASE_MULTIPLIER;
+        user_entry.score += &score_increase;
+
+        self.update_streak(user_id, current_week, &user_entry.raw_gas);
+        self.save_leaderboard_entry(LeaderboardKind::Users, user_entry, current_week);
+
+        score_increase
+    }
+
+    /// Adds to the user's score for the current week, without any raw gas
+    fn credit_leaderboard_score(&self, user_id: AddressId, score: &BigUint) {
+        let current_week = self.get_current_week();
+        let mut user_entry =
+            self.get_leaderboard_entry_or_default(LeaderboardKind::Users, user_id, current_week);
+        user_entry.score += score;
+
+        self.save_leaderboard_entry(LeaderboardKind::Users, user_entry, current_week);
+    }
+
+    fn get_leaderboard_entry_or_default(
+        &self,
+        kind: LeaderboardKind,
+        user_id: AddressId,
+        week: Week,
+    ) -> LeaderboardEntry<Self::Api> {
+        let user_index = self.index_in_leaderboard(kind, user_id, week).get();
+        if user_index == 0 {
+            return LeaderboardEntry {
+                user_id,
+                raw_gas: BigUint::zero(),
+                score: BigUint::zero(),
+            };
+        }
+
+        self.leaderboard_for_kind(kind, week).get(user_index)
+    }
+
+    fn save_leaderboard_entry(
+        &self,
+        kind: LeaderboardKind,
+        user_entry: LeaderboardEntry<Self::Api>,
+        current_week: Week,
+    ) {
+        let user_index = self
+            .index_in_leaderboard(kind, user_entry.user_id, current_week)
+            .get();
+        if user_index == 0 {
+            self.add_new_user_entry(kind, user_entry, current_week);
         } else {
-            self.add_new_user_entry(user_id, amount, current_week);
+            self.update_leaderboard_placement(
+                kind,
+                current_week,
+                &user_entry,
+                user_index,
+                &mut self.leaderboard_for_kind(kind, current_week),
+            );
         }
     }
 
-    fn add_new_user_entry(&self, user_id: AddressId, amount: u64, current_week: Week) {
-        let mut leaderboard_mapper = self.leaderboard(current_week);
-        let new_user_entry = LeaderboardEntry {
-            user_id,
-            work_amount: amount.into(),
-        };
+    fn add_new_user_entry(
+        &self,
+        kind: LeaderboardKind,
+        new_user_entry: LeaderboardEntry<Self::Api>,
+        current_week: Week,
+    ) {
+        let mut leaderboard_mapper = self.leaderboard_for_kind(kind, current_week);
         let _ = leaderboard_mapper.push(&new_user_entry);
 
         let leaderboard_len = leaderboard_mapper.len();
-        self.user_index_in_leaderboard(user_id, current_week)
+        self.index_in_leaderboard(kind, new_user_entry.user_id, current_week)
             .set(leaderboard_len);
 
         if leaderboard_len == 1 {
@@ -52,6 +154,7 @@ pub trait LeaderboardModule:
         }
 
         self.set_new_user_entry_in_mapper(
+            kind,
             current_week,
             &new_user_entry,
             leaderboard_len,
@@ -61,6 +164,7 @@ pub trait LeaderboardModule:
 
     fn set_new_user_entry_in_mapper(
         &self,
+        kind: LeaderboardKind,
         current_week: Week,
         new_user_entry: &LeaderboardEntry<Self::Api>,
         leaderboard_len: usize,
@@ -69,11 +173,11 @@ pub trait LeaderboardModule:
         let mut new_user_index = leaderboard_len;
         for user_index in (1..=leaderboard_len - 1).rev() {
             let existing_user_entry = leaderboard_mapper.get_unchecked(user_index);
-            if new_user_entry.work_amount <= existing_user_entry.work_amount {
+            if new_user_entry.score <= existing_user_entry.score {
                 break;
             }
 
-            self.move_user_down_lb(current_week, &existing_user_entry, leaderboard_mapper);
+            self.move_user_down_lb(kind, current_week, &existing_user_entry, leaderboard_mapper);
             new_user_index -= 1;
         }
 
@@ -81,18 +185,19 @@ pub trait LeaderboardModule:
             leaderboard_mapper.set(new_user_index, new_user_entry);
         }
 
-        self.user_index_in_leaderboard(new_user_entry.user_id, current_week)
+        self.index_in_leaderboard(kind, new_user_entry.user_id, current_week)
             .set(new_user_index);
     }
 
     fn move_user_down_lb(
         &self,
+        kind: LeaderboardKind,
         current_week: Week,
         user_entry: &LeaderboardEntry<Self::Api>,
         leaderboard_mapper: &mut VecMapper<LeaderboardEntry<Self::Api>>,
     ) {
         let prev_user_index = self
-            .user_index_in_leaderboard(user_entry.user_id, current_week)
+            .index_in_leaderboard(kind, user_entry.user_id, current_week)
             .update(|user_index| {
                 let prev_user_index = *user_index;
                 *user_index += 1;
@@ -104,21 +209,9 @@ pub trait LeaderboardModule:
         leaderboard_mapper.set(new_user_index, user_entry);
     }
 
-    fn increase_work_amount(&self, user_index: usize, work_amount: u64, current_week: Week) {
-        let mut leaderboard_mapper = self.leaderboard(current_week);
-        let mut user_entry = leaderboard_mapper.get(user_index);
-        user_entry.work_amount += work_amount;
-
-        self.update_leaderboard_placement(
-            current_week,
-            &user_entry,
-            user_index,
-            &mut leaderboard_mapper,
-        );
-    }
-
     fn update_leaderboard_placement(
         &self,
+        kind: LeaderboardKind,
         current_week: Week,
         updated_user_entry: &LeaderboardEntry<Self::Api>,
         current_user_index: usize,
@@ -127,19 +220,44 @@ pub trait LeaderboardModule:
         let mut new_user_index = current_user_index;
         for user_index in (1..=current_user_index - 1).rev() {
             let existing_user_entry = leaderboard_mapper.get_unchecked(user_index);
-            if updated_user_entry.work_amount <= existing_user_entry.work_amount {
+            if updated_user_entry.score <= existing_user_entry.score {
                 break;
             }
 
-            self.move_user_down_lb(current_week, &existing_user_entry, leaderboard_mapper);
+            self.move_user_down_lb(kind, current_week, &existing_user_entry, leaderboard_mapper);
             new_user_index -= 1;
         }
 
         leaderboard_mapper.set(new_user_index, updated_user_entry);
-        self.user_index_in_leaderboard(updated_user_entry.user_id, current_week)
+        self.index_in_leaderboard(kind, updated_user_entry.user_id, current_week)
             .set(new_user_index);
     }
 
+    fn leaderboard_for_kind(
+        &self,
+        kind: LeaderboardKind,
+        week: Week,
+    ) -> VecMapper<LeaderboardEntry<Self::Api>> {
+        match kind {
+            LeaderboardKind::Users => self.leaderboard(week),
+            LeaderboardKind::Guilds => self.guild_leaderboard(week),
+            LeaderboardKind::Division(division) => self.division_leaderboard(division, week),
+        }
+    }
+
+    fn index_in_leaderboard(
+        &self,
+        kind: LeaderboardKind,
+        id: AddressId,
+        week: Week,
+    ) -> SingleValueMapper<usize> {
+        match kind {
+            LeaderboardKind::Users => self.user_index_in_leaderboard(id, week),
+            LeaderboardKind::Guilds => self.guild_index_in_leaderboard(id, week),
+            LeaderboardKind::Division(_) => self.division_index_in_leaderboard(id, week),
+        }
+    }
+
     #[storage_mapper("userIndexInLb")]
     fn user_index_in_leaderboard(&self, user_id: AddressId, week: Week)
         -> SingleValueMapper<usize>;
@@ -147,4 +265,28 @@ pub trait LeaderboardModule:
     #[view(getLeaderboardForWeek)]
     #[storage_mapper("leaderboard")]
     fn leaderboard(&self, week: Week) -> VecMapper<LeaderboardEntry<Self::Api>>;
+
+    #[storage_mapper("guildIndexInLb")]
+    fn guild_index_in_leaderboard(&self, guild_id: GuildId, week: Week)
+        -> SingleValueMapper<usize>;
+
+    #[view(getGuildLeaderboardForWeek)]
+    #[storage_mapper("guildLeaderboard")]
+    fn guild_leaderboard(&self, week: Week) -> VecMapper<LeaderboardEntry<Self::Api>>;
+
+    /// A user is in a single division for a given week
+    #[storage_mapper("divisionIndexInLb")]
+    fn division_index_in_leaderboard(
+        &self,
+        user_id: AddressId,
+        week: Week,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(getDivisionLeaderboardForWeek)]
+    #[storage_mapper("divisionLeaderboard")]
+    fn division_leaderboard(
+        &self,
+        division: Division,
+        week: Week,
+    ) -> VecMapper<LeaderboardEntry<Self::Api>>;
 }
@@ -0,0 +1,264 @@
+use crate::{leaderboard::LeaderboardKind, staking_pool::MAX_PERCENTAGE, week_timekeeping::Week};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_LEAGUE_MOVES: usize = 50;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Division {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl Division {
+    pub const ALL: [Division; 3] = [Division::Bronze, Division::Silver, Division::Gold];
+
+    pub fn promoted(self) -> Self {
+        match self {
+            Division::Bronze => Division::Silver,
+            Division::Silver | Division::Gold => Division::Gold,
+        }
+    }
+
+    pub fn relegated(self) -> Self {
+        match self {
+            Division::Bronze | Division::Silver => Division::Bronze,
+            Division::Gold => Division::Silver,
+        }
+    }
+}
+
+/// The week's prize pool is split between the divisions by the given percentages,
+/// out of MAX_PERCENTAGE, each division paying its own top users
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct LeagueConfig {
+    pub nr_promoted: usize,
+    pub nr_relegated: usize,
+    pub bronze_pool_percentage: u32,
+    pub silver_pool_percentage: u32,
+    pub gold_pool_percentage: u32,
+}
+
+impl LeagueConfig {
+    pub fn get_pool_percentage(&self, division: Division) -> u32 {
+        match division {
+            Division::Bronze => self.bronze_pool_percentage,
+            Division::Silver => self.silver_pool_percentage,
+            Division::Gold => self.gold_pool_percentage,
+        }
+    }
+}
+
+/// Users start in bronze and play each week in their division's leaderboard
+/// Once a week is over, the top nr_promoted of each division move up a division
+/// and the bottom nr_relegated move down, from their next week of work
+/// Weeks are finalized by the first work of a following week,
+/// unless anyone already did so through finalizeLeagueWeek
+#[multiversx_sc::module]
+pub trait LeagueModule:
+    crate::leaderboard::LeaderboardModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Applies from the next week
+    #[only_owner]
+    #[endpoint(setLeagueConfig)]
+    fn set_league_config(&self, config: LeagueConfig) {
+        require!(
+            config.nr_promoted <= MAX_LEAGUE_MOVES && config.nr_relegated <= MAX_LEAGUE_MOVES,
+            "Too many promotions or relegations"
+        );
+        // checked one by one first, so the sum cannot overflow
+        require!(
+            config.bronze_pool_percentage <= MAX_PERCENTAGE
+                && config.silver_pool_percentage <= MAX_PERCENTAGE
+                && config.gold_pool_percentage <= MAX_PERCENTAGE
+                && config.bronze_pool_percentage
+                    + config.silver_pool_percentage
+                    + config.gold_pool_percentage
+                    == MAX_PERCENTAGE,
+            "Pool percentages must add up to the maximum"
+        );
+
+        self.update_league_week(self.get_current_week());
+        self.league_config().set(config);
+    }
+
+    /// Leagues stop from the next week, the current one is still played in divisions
+    #[only_owner]
+    #[endpoint(clearLeagueConfig)]
+    fn clear_league_config(&self) {
+        require!(!self.league_config().is_empty(), "Leagues not enabled");
+
+        self.update_league_week(self.get_current_week());
+        self.league_config().clear();
+    }
+
+    /// Finalizes the previous league week without waiting for a work
+    #[endpoint(finalizeLeagueWeek)]
+    fn finalize_previous_league_week(&self) {
+        self.update_league_week(self.get_current_week());
+    }
+
+    #[view(getUserDivision)]
+    fn get_user_division(&self, user: ManagedAddress) -> Division {
+        let user_id = self.user_id().get_id(&user);
+        self.user_division(user_id).get()
+    }
+
+    fn is_league_week(&self, week: Week) -> bool {
+        !self.league_config_for_week(week).is_empty()
+    }
+
+    /// The leaderboard the user's rewards for the week are paid from
+    fn get_prize_leaderboard_kind(&self, user_id: AddressId, week: Week) -> LeaderboardKind {
+        if !self.is_league_week(week) {
+            return LeaderboardKind::Users;
+        }
+
+        LeaderboardKind::Division(self.user_division_for_week(user_id, week).get())
+    }
+
+    fn increase_division_entry(
+        &self,
+        user_id: AddressId,
+        gas_amount: u64,
+        score_increase: &BigUint,
+    ) {
+        let current_week = self.get_current_week();
+        self.update_league_week(current_week);
+        if !self.is_league_week(current_week) {
+            return;
+        }
+
+        let user_division_for_week_mapper = self.user_division_for_week(user_id, current_week);
+        if self
+            .division_index_in_leaderboard(user_id, current_week)
+            .get()
+            == 0
+        {
+            user_division_for_week_mapper.set(self.user_division(user_id).get());
+        }
+
+        let kind = LeaderboardKind::Division(user_division_for_week_mapper.get());
+        let mut user_entry = self.get_leaderboard_entry_or_default(kind, user_id, current_week);
+        user_entry.raw_gas += gas_amount;
+        user_entry.score += score_increase;
+        self.save_leaderboard_entry(kind, user_entry, current_week);
+    }
+
+    /// The first call of a week finalizes the previous league week
+    /// and snapshots the config for the current one
+    fn update_league_week(&self, current_week: Week) {
+        let league_week_mapper = self.league_week();
+        let league_week = league_week_mapper.get();
+        if league_week == current_week {
+            return;
+        }
+
+        if league_week != 0 && self.is_league_week(league_week) {
+            self.finalize_league_week(league_week);
+        }
+
+        let config_mapper = self.league_config();
+        if !config_mapper.is_empty() {
+            self.league_config_for_week(current_week)
+                .set(config_mapper.get());
+        }
+        league_week_mapper.set(current_week);
+    }
+
+    /// The top users are never relegated, even in a small division
+    fn finalize_league_week(&self, week: Week) {
+        let config = self.league_config_for_week(week).get();
+        for division in Division::ALL {
+            let leaderboard_mapper = self.division_leaderboard(division, week);
+            let leaderboard_len = leaderboard_mapper.len();
+            let nr_promoted = core::cmp::min(config.nr_promoted, leaderboard_len);
+            if division != Division::Gold {
+                for i in 1..=nr_promoted {
+                    let entry = leaderboard_mapper.get_unchecked(i);
+                    self.user_division(entry.user_id).set(division.promoted());
+                }
+            }
+
+            if division != Division::Bronze {
+                let first_relegated = core::cmp::max(
+                    leaderboard_len.saturating_sub(config.nr_relegated),
+                    nr_promoted,
+                ) + 1;
+                for i in first_relegated..=leaderboard_len {
+                    let entry = leaderboard_mapper.get_unchecked(i);
+                    self.user_division(entry.user_id).set(division.relegated());
+                }
+            }
+        }
+    }
+
+    /// Division entries are pruned along with the global ones, which hold the same users
+    /// The user's own entry is removed, the division's last entry taking its place
+    fn prune_division_entry(&self, user_id: AddressId, week: Week) {
+        let index = self.division_index_in_leaderboard(user_id, week).take();
+        let division = self.user_division_for_week(user_id, week).take();
+        if index == 0 {
+            return;
+        }
+
+        let mut leaderboard_mapper = self.division_leaderboard(division, week);
+        let last_index = leaderboard_mapper.len();
+        if index != last_index {
+            let last_entry = leaderboard_mapper.get(last_index);
+            self.division_index_in_leaderboard(last_entry.user_id, week)
+                .set(index);
+        }
+        leaderboard_mapper.swap_remove(index);
+    }
+
+    fn calculate_top_total_division_score_for_week(
+        &self,
+        division: Division,
+        week: Week,
+        nr_top_users: usize,
+    ) -> BigUint {
+        let leaderboard_mapper = self.division_leaderboard(division, week);
+        let last_entry_index = core::cmp::min(leaderboard_mapper.len(), nr_top_users);
+        let mut total_score = BigUint::zero();
+        for i in 1..=last_entry_index {
+            total_score += leaderboard_mapper.get_unchecked(i).score;
+        }
+
+        total_score
+    }
+
+    #[view(getLeagueConfig)]
+    #[storage_mapper("leagueConfig")]
+    fn league_config(&self) -> SingleValueMapper<LeagueConfig>;
+
+    /// Empty for weeks played without leagues
+    #[view(getLeagueConfigForWeek)]
+    #[storage_mapper("leagueConfigForWeek")]
+    fn league_config_for_week(&self, week: Week) -> SingleValueMapper<LeagueConfig>;
+
+    /// Latest week with work since leagues were introduced
+    #[storage_mapper("leagueWeek")]
+    fn league_week(&self) -> SingleValueMapper<Week>;
+
+    #[storage_mapper("userDivision")]
+    fn user_division(&self, user_id: AddressId) -> SingleValueMapper<Division>;
+
+    #[storage_mapper("userDivisionForWeek")]
+    fn user_division_for_week(&self, user_id: AddressId, week: Week)
+        -> SingleValueMapper<Division>;
+}
@@ -2,9 +2,29 @@
 
 multiversx_sc::imports!();
 
+pub mod boost;
+pub mod guild;
 pub mod leaderboard;
+pub mod league;
+pub mod locked_token;
+pub mod migration;
+pub mod owner_sc_proxy;
+pub mod pause_flags;
+pub mod proof_of_work;
+pub mod pruning;
+pub mod recovery;
+pub mod referral;
 pub mod rewards;
+pub mod scoring;
+pub mod session_key;
 pub mod signature;
+pub mod signed_message;
+pub mod signers;
+pub mod staking;
+pub mod staking_pool;
+pub mod storage_version;
+pub mod streak;
+pub mod token;
 pub mod week_timekeeping;
 pub mod work;
 
@@ -12,29 +32,81 @@ pub mod work;
 pub trait GasBurner:
     work::WorkModule
     + leaderboard::LeaderboardModule
+    + league::LeagueModule
+    + guild::GuildModule
     + rewards::RewardsModule
+    + boost::BoostModule
+    + recovery::RecoveryModule
+    + referral::ReferralModule
+    + pruning::PruningModule
     + signature::SignatureModule
+    + signers::SignersModule
+    + proof_of_work::ProofOfWorkModule
+    + session_key::SessionKeyModule
     + week_timekeeping::WeekTimekeepingModule
+    + pause_flags::PauseFlagsModule
+    + storage_version::StorageVersionModule
+    + streak::StreakModule
+    + staking::StakingModule
+    + scoring::ScoringModule
+    + staking_pool::StakingPoolModule
+    + token::TokenModule
+    + locked_token::LockedTokenModule
+    + migration::MigrationModule
     + multiversx_sc_modules::pause::PauseModule
 {
+    /// The token may be issued beforehand, with the Mint role granted externally,
+    /// or later through issueToken
+    /// The chain id is the one signers include in their messages
     #[init]
-    fn init(&self, owner_sc: ManagedAddress, signer: ManagedAddress, token_id: TokenIdentifier) {
-        require!(token_id.is_valid_esdt_identifier(), "Invalid ESDT token");
+    fn init(
+        &self,
+        owner_sc: ManagedAddress,
+        signer: ManagedAddress,
+        chain_id: ManagedBuffer,
+        opt_token_id: OptionalValue<TokenIdentifier>,
+    ) {
         require!(
             !owner_sc.is_zero() && self.blockchain().is_smart_contract(&owner_sc),
             "Invalid SC address"
         );
 
         self.owner_sc().set(owner_sc);
-        self.signer().set(signer);
-        self.token().set_token_id(token_id);
+        let _ = self.add_signer_common(signer);
+        self.set_chain_id_common(chain_id);
+        if let OptionalValue::Some(token_id) = opt_token_id {
+            require!(token_id.is_valid_esdt_identifier(), "Invalid ESDT token");
+            self.token().set_token_id(token_id);
+        }
 
         let current_epoch = self.blockchain().get_block_epoch();
         self.first_week_start_epoch().set(current_epoch);
 
+        self.claim_window_weeks()
+            .set(rewards::DEFAULT_CLAIM_WINDOW_WEEKS);
+
         self.set_paused(true);
+        self.storage_version()
+            .set(storage_version::CURRENT_STORAGE_VERSION);
     }
 
+    /// Work and claims are disabled until the migration completes,
+    /// use continueMigration if it does not fit in the upgrade transaction
+    /// Weeks from before the claim window was introduced count their window from the upgrade
+    /// The chain id must be given if not set yet, so signed work keeps going through
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, opt_chain_id: OptionalValue<ManagedBuffer>) {
+        if let OptionalValue::Some(chain_id) = opt_chain_id {
+            self.set_chain_id_common(chain_id);
+        }
+        require!(!self.chain_id().is_empty(), "Chain id not set");
+
+        let claim_window_mapper = self.claim_window_weeks();
+        if claim_window_mapper.is_empty() {
+            claim_window_mapper.set(rewards::DEFAULT_CLAIM_WINDOW_WEEKS);
+            self.claim_window_start_week().set(self.get_current_week());
+        }
+
+        let _ = self.run_migrations(migration::WEEKS_PER_MIGRATION_STEP_ON_UPGRADE);
+    }
 }
@@ -0,0 +1,137 @@
+use crate::week_timekeeping::Epoch;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub struct LockSchedule {
+    pub lock_epochs: Epoch,
+    pub vesting_epochs: Epoch,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum MintMode {
+    Liquid,
+    Locked(LockSchedule),
+}
+
+/// Nothing unlocks before the start epoch, everything is unlocked at the end epoch
+/// and the amount in between vests linearly
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub struct LockedTokenAttributes {
+    pub vesting_start_epoch: Epoch,
+    pub vesting_end_epoch: Epoch,
+}
+
+#[multiversx_sc::module]
+pub trait LockedTokenModule:
+    crate::token::TokenModule + multiversx_sc_modules::pause::PauseModule
+{
+    /// In locked mode, rewards minted by work are locked according to the schedule
+    #[only_owner]
+    #[endpoint(setMintMode)]
+    fn set_mint_mode(&self, mode: MintMode) {
+        if let MintMode::Locked(schedule) = mode {
+            require!(
+                schedule.lock_epochs > 0 || schedule.vesting_epochs > 0,
+                "Invalid lock schedule"
+            );
+        }
+
+        self.mint_mode().set(mode);
+    }
+
+    /// Burns the locked tokens and sends the liquid amount vested so far
+    /// The still locked remainder is sent back as new locked tokens,
+    /// vesting from the current epoch
+    #[payable("*")]
+    #[endpoint]
+    fn unlock(&self) -> EsdtTokenPayment {
+        self.require_not_paused();
+
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No payment");
+
+        let caller = self.blockchain().get_caller();
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut total_unlocked = BigUint::zero();
+        for payment in payments.iter() {
+            self.locked_token()
+                .require_same_token(&payment.token_identifier);
+
+            let attributes: LockedTokenAttributes = self
+                .locked_token()
+                .get_token_attributes(payment.token_nonce);
+            let unlocked_amount =
+                self.get_unlocked_amount(&payment.amount, &attributes, current_epoch);
+            require!(unlocked_amount > 0, "Nothing to unlock yet");
+
+            self.locked_token()
+                .nft_burn(payment.token_nonce, &payment.amount);
+
+            let locked_remainder = &payment.amount - &unlocked_amount;
+            if locked_remainder > 0 {
+                let remainder_attributes = LockedTokenAttributes {
+                    vesting_start_epoch: current_epoch,
+                    vesting_end_epoch: attributes.vesting_end_epoch,
+                };
+                let _ = self.locked_token().nft_create_and_send(
+                    &caller,
+                    locked_remainder,
+                    &remainder_attributes,
+                );
+            }
+
+            total_unlocked += unlocked_amount;
+        }
+
+        self.require_mint_role();
+        self.token().mint_and_send(&caller, total_unlocked)
+    }
+
+    fn mint_rewards(&self, user: &ManagedAddress, amount: BigUint) -> EsdtTokenPayment {
+        match self.mint_mode().get() {
+            MintMode::Liquid => {
+                self.require_mint_role();
+                self.token().mint_and_send(user, amount)
+            }
+            MintMode::Locked(schedule) => {
+                self.require_locked_token_roles();
+
+                let vesting_start_epoch =
+                    self.blockchain().get_block_epoch() + schedule.lock_epochs;
+                let attributes = LockedTokenAttributes {
+                    vesting_start_epoch,
+                    vesting_end_epoch: vesting_start_epoch + schedule.vesting_epochs,
+                };
+                self.locked_token()
+                    .nft_create_and_send(user, amount, &attributes)
+            }
+        }
+    }
+
+    fn get_unlocked_amount(
+        &self,
+        locked_amount: &BigUint,
+        attributes: &LockedTokenAttributes,
+        current_epoch: Epoch,
+    ) -> BigUint {
+        if current_epoch >= attributes.vesting_end_epoch {
+            return locked_amount.clone();
+        }
+        if current_epoch <= attributes.vesting_start_epoch {
+            return BigUint::zero();
+        }
+
+        let vested_epochs = current_epoch - attributes.vesting_start_epoch;
+        let vesting_epochs = attributes.vesting_end_epoch - attributes.vesting_start_epoch;
+        locked_amount * vested_epochs / vesting_epochs
+    }
+
+    #[view(getMintMode)]
+    #[storage_mapper("mintMode")]
+    fn mint_mode(&self) -> SingleValueMapper<MintMode>;
+}
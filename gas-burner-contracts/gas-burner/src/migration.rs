@@ -0,0 +1,235 @@
+use crate::{
+    leaderboard::LeaderboardEntry,
+    pruning::{LeaderboardHash, WeekSummary},
+    rewards::TOP_LEADERBOARD_USERS_FOR_PRIZES,
+    storage_version::{
+        CURRENT_STORAGE_VERSION, LEGACY_STORAGE_VERSION, PER_USER_CLAIMS_STORAGE_VERSION,
+    },
+    week_timekeeping::Week,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const WEEKS_PER_MIGRATION_STEP_ON_UPGRADE: usize = 20;
+const MIN_GAS_FOR_MIGRATED_WEEK: u64 = 5_000_000;
+const MIN_GAS_FOR_MIGRATED_ENTRY: u64 = 1_000_000;
+
+/// Leaderboard entry of storage versions 1 and 2, the work amount was the raw gas
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug,
+)]
+pub struct LegacyLeaderboardEntry<M: ManagedTypeApi> {
+    pub user_id: AddressId,
+    pub work_amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> From<LegacyLeaderboardEntry<M>> for LeaderboardEntry<M> {
+    fn from(legacy_entry: LegacyLeaderboardEntry<M>) -> Self {
+        LeaderboardEntry {
+            user_id: legacy_entry.user_id,
+            raw_gas: legacy_entry.work_amount.clone(),
+            score: legacy_entry.work_amount,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct LegacyWeekSummary<M: ManagedTypeApi> {
+    pub winners: ManagedVec<M, LegacyLeaderboardEntry<M>>,
+    pub nr_participants: usize,
+    pub total_top_work: BigUint<M>,
+    pub total_rewards: BigUint<M>,
+    pub rewards_paid: BigUint<M>,
+    pub leaderboard_hash: LeaderboardHash<M>,
+}
+
+/// Storage layout changes are migrated one version at a time
+/// Large migrations are split across transactions, see continueMigration
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::storage_version::StorageVersionModule
+    + crate::rewards::RewardsModule
+    + crate::pruning::PruningModule
+    + crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Anyone may push an unfinished migration forward
+    /// Returns true once the storage is fully migrated
+    #[endpoint(continueMigration)]
+    fn continue_migration(&self, max_weeks: usize) -> bool {
+        require!(max_weeks > 0, "Invalid number of weeks");
+
+        self.run_migrations(max_weeks)
+    }
+
+    fn run_migrations(&self, mut max_weeks: usize) -> bool {
+        let storage_version_mapper = self.storage_version();
+        if storage_version_mapper.is_empty() {
+            storage_version_mapper.set(LEGACY_STORAGE_VERSION);
+        }
+
+        loop {
+            let storage_version = storage_version_mapper.get();
+            let is_step_complete = match storage_version {
+                CURRENT_STORAGE_VERSION => return true,
+                LEGACY_STORAGE_VERSION => self.migrate_to_v2(&mut max_weeks),
+                PER_USER_CLAIMS_STORAGE_VERSION => self.migrate_to_v3(&mut max_weeks),
+                _ => sc_panic!("Unknown storage version"),
+            };
+            if !is_step_complete {
+                return false;
+            }
+
+            self.migration_cursor().clear();
+            self.migration_entry_cursor().clear();
+            storage_version_mapper.set(storage_version + 1);
+        }
+    }
+
+    /// Moves the single signer to the signers list,
+    /// and rebuilds the outstanding rewards from the unpaid rewards of every past week
+    /// The per week claimed flags are kept as they are, as they do not tell who claimed,
+    /// see resolveLegacyClaim
+    fn migrate_to_v2(&self, max_weeks: &mut usize) -> bool {
+        let legacy_signer_mapper = self.legacy_signer();
+        if !legacy_signer_mapper.is_empty() {
+            let signer = legacy_signer_mapper.take();
+            let _ = self.add_signer_common(signer);
+        }
+
+        let cursor_mapper = self.migration_cursor();
+        if cursor_mapper.is_empty() {
+            self.outstanding_rewards().clear();
+        }
+
+        let mut week = core::cmp::max(cursor_mapper.get(), 1);
+        let current_week = self.get_current_week();
+        while week < current_week {
+            if *max_weeks == 0 || self.blockchain().get_gas_left() < MIN_GAS_FOR_MIGRATED_WEEK {
+                cursor_mapper.set(week);
+                return false;
+            }
+
+            let total_rewards = self.total_rewards_week(week).get();
+            let rewards_paid = self.rewards_paid_for_week(week).get();
+            if total_rewards > rewards_paid {
+                self.outstanding_rewards()
+                    .update(|outstanding| *outstanding += total_rewards - rewards_paid);
+            }
+
+            week += 1;
+            *max_weeks -= 1;
+        }
+
+        true
+    }
+
+    /// Rewrites the leaderboard entries and pruned week summaries in the new layout,
+    /// with the previous work amount as both raw gas and score
+    /// Summary hashes keep committing to the entries in the previous layout
+    /// The current week is migrated as well, so work is disabled until the migration completes
+    fn migrate_to_v3(&self, max_weeks: &mut usize) -> bool {
+        let cursor_mapper = self.migration_cursor();
+        let entry_cursor_mapper = self.migration_entry_cursor();
+        let mut week = core::cmp::max(cursor_mapper.get(), 1);
+        let current_week = self.get_current_week();
+        while week <= current_week {
+            if *max_weeks == 0 || self.blockchain().get_gas_left() < MIN_GAS_FOR_MIGRATED_WEEK {
+                cursor_mapper.set(week);
+                return false;
+            }
+
+            let legacy_leaderboard_mapper = self.legacy_leaderboard(week);
+            let mut leaderboard_mapper = self.leaderboard(week);
+            let mut entry_index = core::cmp::max(entry_cursor_mapper.get(), 1);
+            while entry_index <= legacy_leaderboard_mapper.len() {
+                if self.blockchain().get_gas_left() < MIN_GAS_FOR_MIGRATED_ENTRY {
+                    cursor_mapper.set(week);
+                    entry_cursor_mapper.set(entry_index);
+                    return false;
+                }
+
+                let legacy_entry = legacy_leaderboard_mapper.get_unchecked(entry_index);
+                leaderboard_mapper.set(entry_index, &LeaderboardEntry::from(legacy_entry));
+                entry_index += 1;
+            }
+            entry_cursor_mapper.clear();
+
+            let legacy_summary_mapper = self.legacy_week_summary(week);
+            if !legacy_summary_mapper.is_empty() {
+                let legacy_summary = legacy_summary_mapper.get();
+                let mut winners = ManagedVec::new();
+                for legacy_entry in legacy_summary.winners.iter() {
+                    winners.push(LeaderboardEntry::from(legacy_entry));
+                }
+
+                self.week_summary(week).set(WeekSummary {
+                    winners,
+                    nr_participants: legacy_summary.nr_participants,
+                    total_top_work: legacy_summary.total_top_work,
+                    total_rewards: legacy_summary.total_rewards,
+                    rewards_paid: legacy_summary.rewards_paid,
+                    leaderboard_hash: legacy_summary.leaderboard_hash,
+                });
+            }
+
+            week += 1;
+            *max_weeks -= 1;
+        }
+
+        true
+    }
+
+    /// Settles a week claimed for under storage version 1, given the user who claimed,
+    /// as found in the week's claim transactions
+    /// Until then, the week's other top users cannot claim, nor lose their rewards
+    #[only_owner]
+    #[endpoint(resolveLegacyClaim)]
+    fn resolve_legacy_claim(&self, week: Week, user: ManagedAddress) {
+        self.require_storage_migrated();
+        require!(
+            self.legacy_user_claimed_for_week(week).take(),
+            "No legacy claim for the week"
+        );
+
+        let user_id = self.user_id().get_id_non_zero(&user);
+        let user_index_in_lb = self.user_index_in_leaderboard(user_id, week).get();
+        require!(
+            user_index_in_lb != 0 && user_index_in_lb <= TOP_LEADERBOARD_USERS_FOR_PRIZES,
+            "User not rewarded for the week"
+        );
+
+        let total_rewards_for_week = self.total_rewards_week(week).get();
+        let user_entry = self.leaderboard(week).get_unchecked(user_index_in_lb);
+        let user_rewards = &total_rewards_for_week * &user_entry.score
+            / self.calculate_top_total_user_work_for_week(week);
+        self.record_rewards_paid(week, &total_rewards_for_week, &user_rewards);
+        self.user_claimed_for_week(user_id, week).set(true);
+    }
+
+    /// Next week to migrate
+    #[storage_mapper("migrationCursor")]
+    fn migration_cursor(&self) -> SingleValueMapper<Week>;
+
+    /// Next leaderboard entry to migrate, within the cursor's week
+    #[storage_mapper("migrationEntryCursor")]
+    fn migration_entry_cursor(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("leaderboard")]
+    fn legacy_leaderboard(&self, week: Week) -> VecMapper<LegacyLeaderboardEntry<Self::Api>>;
+
+    #[storage_mapper("weekSummary")]
+    fn legacy_week_summary(&self, week: Week) -> SingleValueMapper<LegacyWeekSummary<Self::Api>>;
+}
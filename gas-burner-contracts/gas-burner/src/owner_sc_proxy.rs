@@ -0,0 +1,525 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct OwnerScProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for OwnerScProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = OwnerScProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        OwnerScProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct OwnerScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> OwnerScProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// The deployer starts with every admin role 
+    /// Further admins are added through proposals 
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> OwnerScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> OwnerScProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Only the rewards claimed for the calling gas burner are split, 
+    /// the rest of the balance is left untouched 
+    /// Returns the prize pool share, which is forwarded to the gas burner 
+    pub fn claim_dev_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimDevRewards")
+            .original_result()
+    }
+
+    /// Cumulative amount claimed for the gas burner, before the split 
+    pub fn dev_rewards_claimed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        gas_burner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDevRewardsClaimed")
+            .argument(&gas_burner)
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<AdminRole>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleMembers")
+            .argument(&role)
+            .original_result()
+    }
+
+    /// Emergency pause, instant for any pauser 
+    /// Unpausing goes through a proposal 
+    pub fn pause_gas_burner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        gas_burner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseGasBurner")
+            .argument(&gas_burner)
+            .original_result()
+    }
+
+    pub fn pause_all_gas_burners(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseAllGasBurners")
+            .original_result()
+    }
+
+    /// Pauses a single feature, instant for any pauser 
+    pub fn pause_gas_burner_flag<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<PauseFlag>,
+    >(
+        self,
+        gas_burner: Arg0,
+        flag: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseGasBurnerFlag")
+            .argument(&gas_burner)
+            .argument(&flag)
+            .original_result()
+    }
+
+    pub fn gas_burners(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGasBurners")
+            .original_result()
+    }
+
+    /// Only admins with the role required by the action may propose and approve it 
+    /// The proposer's approval is counted automatically 
+    pub fn propose<
+        Arg0: ProxyArg<ProposalAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("propose")
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn approve<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approve")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Only the proposer may cancel, so no single admin can veto an approved proposal 
+    pub fn cancel_proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Anyone may execute a proposal once it has enough approvals and its timelock expired 
+    /// Approvals are counted again against the current quorum, 
+    /// ignoring approvers who no longer hold the required role 
+    /// Async actions keep the proposal in progress until their callback, 
+    /// which removes it on success and makes it executable again on failure 
+    pub fn execute_proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn proposal_approvers<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalApprovers")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Set while an async action awaits its callback 
+    pub fn proposal_in_progress<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isProposalInProgress")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn last_proposal_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastProposalId")
+            .original_result()
+    }
+
+    pub fn approval_quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getApprovalQuorum")
+            .original_result()
+    }
+
+    /// Delay in seconds between a proposal reaching the quorum and it becoming executable 
+    pub fn timelock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelock")
+            .original_result()
+    }
+
+    pub fn deposit_reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositReserve")
+            .original_result()
+    }
+
+    pub fn reserve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserve")
+            .original_result()
+    }
+
+    pub fn dev_rewards_split(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DevRewardsSplit<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDevRewardsSplit")
+            .original_result()
+    }
+
+    pub fn dev_rewards_claim<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        claim_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DevRewardsClaim<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDevRewardsClaim")
+            .argument(&claim_id)
+            .original_result()
+    }
+
+    pub fn last_dev_rewards_claim_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastDevRewardsClaimId")
+            .original_result()
+    }
+
+    pub fn get_recoverable_balance<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecoverableBalance")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum AdminRole {
+    Pauser,
+    Configurator,
+    Treasurer,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum PauseFlag {
+    Work,
+    Claims,
+    DevRewardsCollection,
+    Registration,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum ProposalAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    UnpauseGasBurner(ManagedAddress<Api>),
+    UnpauseAllGasBurners,
+    UnpauseGasBurnerFlag(ManagedAddress<Api>, PauseFlag),
+    AddGasBurner(ManagedAddress<Api>),
+    RemoveGasBurner(ManagedAddress<Api>),
+    AddSigner(ManagedAddress<Api>, ManagedAddress<Api>),
+    RemoveSigner(ManagedAddress<Api>, u32),
+    RotateSigner(ManagedAddress<Api>, u32, ManagedAddress<Api>),
+    SetSignatureThreshold(ManagedAddress<Api>, usize),
+    SetChainId(ManagedAddress<Api>, ManagedBuffer<Api>),
+    SetWorkVerificationMode(ManagedAddress<Api>, WorkVerificationMode),
+    SetPowConfig(ManagedAddress<Api>, u32, u64),
+    SetMintMode(ManagedAddress<Api>, MintMode),
+    UpgradeGasBurner(GasBurnerUpgrade<Api>),
+    TransferGasBurnerOwnership(ManagedAddress<Api>, ManagedAddress<Api>),
+    IssueGasBurnerToken(GasBurnerTokenIssue<Api>),
+    CallGasBurner(ManagedAddress<Api>, ManagedBuffer<Api>, ManagedVec<Api, ManagedBuffer<Api>>),
+    GrantRole(ManagedAddress<Api>, AdminRole),
+    RevokeRole(ManagedAddress<Api>, AdminRole),
+    SetApprovalQuorum(usize),
+    SetTimelock(u64),
+    SetDevRewardsSplit(DevRewardsSplit<Api>),
+    WithdrawReserve(BigUint<Api>, ManagedAddress<Api>),
+    RecoverFunds(EgldOrEsdtTokenIdentifier<Api>, u64, BigUint<Api>, ManagedAddress<Api>),
+    RecoverGasBurnerFunds(ManagedAddress<Api>, EgldOrEsdtTokenIdentifier<Api>, u64, BigUint<Api>, ManagedAddress<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum WorkVerificationMode {
+    Signature,
+    ProofOfWork,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum MintMode {
+    Liquid,
+    Locked(LockSchedule),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub struct LockSchedule {
+    pub lock_epochs: u64,
+    pub vesting_epochs: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct GasBurnerUpgrade<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub gas_burner: ManagedAddress<Api>,
+    pub source: UpgradeSource<Api>,
+    pub code_metadata: CodeMetadata,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum UpgradeSource<Api>
+where
+    Api: ManagedTypeApi,
+{
+    SourceAddress(ManagedAddress<Api>),
+    Code(ManagedBuffer<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct GasBurnerTokenIssue<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub gas_burner: ManagedAddress<Api>,
+    pub token_kind: GasBurnerTokenKind,
+    pub token_display_name: ManagedBuffer<Api>,
+    pub token_ticker: ManagedBuffer<Api>,
+    pub num_decimals: usize,
+    pub issue_cost: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum GasBurnerTokenKind {
+    Liquid,
+    Locked,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DevRewardsSplit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub treasury: ManagedAddress<Api>,
+    pub treasury_percentage: u32,
+    pub buyback: ManagedAddress<Api>,
+    pub buyback_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action: ProposalAction<Api>,
+    pub proposer: ManagedAddress<Api>,
+    pub executable_after: Option<u64>,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct DevRewardsClaim<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub gas_burner: ManagedAddress<Api>,
+    pub prize_pool_amount: BigUint<Api>,
+    pub treasury_amount: BigUint<Api>,
+    pub buyback_amount: BigUint<Api>,
+}
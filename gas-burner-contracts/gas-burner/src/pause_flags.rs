@@ -0,0 +1,73 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum PauseFlag {
+    Work,
+    Claims,
+    DevRewardsCollection,
+    Registration,
+}
+
+impl PauseFlag {
+    pub fn paused_err_msg(&self) -> &'static str {
+        match self {
+            PauseFlag::Work => "Work is paused",
+            PauseFlag::Claims => "Claims are paused",
+            PauseFlag::DevRewardsCollection => "Dev rewards collection is paused",
+            PauseFlag::Registration => "Registration is paused",
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PauseStatus {
+    pub global: bool,
+    pub work: bool,
+    pub claims: bool,
+    pub dev_rewards_collection: bool,
+    pub registration: bool,
+}
+
+/// Granular switches on top of the global pause, which still stops everything
+#[multiversx_sc::module]
+pub trait PauseFlagsModule: multiversx_sc_modules::pause::PauseModule {
+    #[only_owner]
+    #[endpoint(pauseFlag)]
+    fn pause_flag(&self, flag: PauseFlag) {
+        self.paused_flag(flag).set(true);
+    }
+
+    #[only_owner]
+    #[endpoint(unpauseFlag)]
+    fn unpause_flag(&self, flag: PauseFlag) {
+        self.paused_flag(flag).clear();
+    }
+
+    fn require_flag_not_paused(&self, flag: PauseFlag) {
+        self.require_not_paused();
+        if self.paused_flag(flag).get() {
+            sc_panic!(flag.paused_err_msg());
+        }
+    }
+
+    fn is_flag_active(&self, flag: PauseFlag) -> bool {
+        !self.is_paused() && !self.paused_flag(flag).get()
+    }
+
+    #[view(getPauseStatus)]
+    fn get_pause_status(&self) -> PauseStatus {
+        PauseStatus {
+            global: self.is_paused(),
+            work: self.paused_flag(PauseFlag::Work).get(),
+            claims: self.paused_flag(PauseFlag::Claims).get(),
+            dev_rewards_collection: self.paused_flag(PauseFlag::DevRewardsCollection).get(),
+            registration: self.paused_flag(PauseFlag::Registration).get(),
+        }
+    }
+
+    #[storage_mapper("pausedFlag")]
+    fn paused_flag(&self, flag: PauseFlag) -> SingleValueMapper<bool>;
+}
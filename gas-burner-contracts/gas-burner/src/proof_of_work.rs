@@ -0,0 +1,199 @@
+use crate::{signature::Nonce, week_timekeeping::Week};
+use multiversx_sc::api::KECCAK256_RESULT_LEN;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type Difficulty = u32;
+
+pub const MIN_POW_DIFFICULTY: Difficulty = 1;
+pub const MAX_POW_DIFFICULTY: Difficulty = 64;
+pub const MAX_POW_SOLUTION_LEN: usize = 32;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum WorkVerificationMode {
+    Signature,
+    ProofOfWork,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PowConfig {
+    pub initial_difficulty: Difficulty,
+    pub target_submissions_per_week: u64,
+}
+
+#[multiversx_sc::module]
+pub trait ProofOfWorkModule:
+    crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+{
+    #[only_owner]
+    #[endpoint(setWorkVerificationMode)]
+    fn set_work_verification_mode(&self, mode: WorkVerificationMode) {
+        if mode == WorkVerificationMode::ProofOfWork {
+            require!(
+                !self.pow_config().is_empty(),
+                "Proof of work not configured"
+            );
+        }
+
+        self.work_verification_mode().set(mode);
+    }
+
+    /// Difficulty is the number of leading zero bits required in the solution hash
+    #[only_owner]
+    #[endpoint(setPowConfig)]
+    fn set_pow_config(&self, initial_difficulty: Difficulty, target_submissions_per_week: u64) {
+        require!(
+            (MIN_POW_DIFFICULTY..=MAX_POW_DIFFICULTY).contains(&initial_difficulty),
+            "Invalid difficulty"
+        );
+        require!(
+            target_submissions_per_week > 0,
+            "Invalid target submissions"
+        );
+
+        self.pow_config().set(PowConfig {
+            initial_difficulty,
+            target_submissions_per_week,
+        });
+    }
+
+    /// The solution is valid if keccak256(user_address + user_nonce + week_seed + solution)
+    /// has at least `difficulty` leading zero bits. The nonce is encoded as 8 big-endian bytes
+    fn check_proof_of_work(
+        &self,
+        user: &ManagedAddress,
+        user_nonce: Nonce,
+        solution: &ManagedBuffer,
+    ) {
+        require!(
+            solution.len() <= MAX_POW_SOLUTION_LEN,
+            "Proof of work solution too long"
+        );
+
+        let current_week = self.get_current_week();
+        let difficulty = self.get_or_init_pow_difficulty(current_week);
+        let pow_hash = self.compute_pow_hash(user, user_nonce, current_week, solution);
+        require!(
+            self.has_leading_zero_bits(&pow_hash, difficulty),
+            "Invalid proof of work"
+        );
+    }
+
+    fn compute_pow_hash(
+        &self,
+        user: &ManagedAddress,
+        user_nonce: Nonce,
+        week: Week,
+        solution: &ManagedBuffer,
+    ) -> ManagedByteArray<KECCAK256_RESULT_LEN> {
+        let mut pow_message = user.as_managed_buffer().clone();
+        let _ = user_nonce.dep_encode(&mut pow_message);
+        pow_message.append(self.get_week_seed(week).as_managed_buffer());
+        pow_message.append(solution);
+
+        self.crypto().keccak256(pow_message)
+    }
+
+    fn has_leading_zero_bits(
+        &self,
+        hash: &ManagedByteArray<KECCAK256_RESULT_LEN>,
+        difficulty: Difficulty,
+    ) -> bool {
+        let mut zero_bits = 0;
+        for byte in hash.to_byte_array() {
+            zero_bits += byte.leading_zeros();
+            if byte != 0 || zero_bits >= difficulty {
+                break;
+            }
+        }
+
+        zero_bits >= difficulty
+    }
+
+    /// The seed depends on the previous week's submission count,
+    /// so solutions can't be precomputed before the week starts
+    #[view(getWeekSeed)]
+    fn get_week_seed(&self, week: Week) -> ManagedByteArray<KECCAK256_RESULT_LEN> {
+        let mut seed_data = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        let _ = (week as u64).dep_encode(&mut seed_data);
+        if week > 1 {
+            let _ = self
+                .submissions_for_week(week - 1)
+                .get()
+                .dep_encode(&mut seed_data);
+        }
+
+        self.crypto().keccak256(seed_data)
+    }
+
+    #[view(getPowDifficulty)]
+    fn get_pow_difficulty(&self, week: Week) -> Difficulty {
+        let difficulty_mapper = self.pow_difficulty_for_week(week);
+        if !difficulty_mapper.is_empty() {
+            return difficulty_mapper.get();
+        }
+
+        self.compute_pow_difficulty(week)
+    }
+
+    fn get_or_init_pow_difficulty(&self, week: Week) -> Difficulty {
+        let difficulty_mapper = self.pow_difficulty_for_week(week);
+        if !difficulty_mapper.is_empty() {
+            return difficulty_mapper.get();
+        }
+
+        let difficulty = self.compute_pow_difficulty(week);
+        difficulty_mapper.set(difficulty);
+
+        difficulty
+    }
+
+    /// Difficulty goes up by one bit if the previous week had more submissions than the target,
+    /// and down by one bit if it had less than half the target
+    fn compute_pow_difficulty(&self, week: Week) -> Difficulty {
+        let pow_config = self.pow_config().get();
+        if week <= 1 {
+            return pow_config.initial_difficulty;
+        }
+
+        let previous_week = week - 1;
+        let previous_difficulty_mapper = self.pow_difficulty_for_week(previous_week);
+        if previous_difficulty_mapper.is_empty() {
+            return pow_config.initial_difficulty;
+        }
+
+        let previous_difficulty = previous_difficulty_mapper.get();
+        let previous_submissions = self.submissions_for_week(previous_week).get();
+        if previous_submissions > pow_config.target_submissions_per_week {
+            core::cmp::min(previous_difficulty + 1, MAX_POW_DIFFICULTY)
+        } else if previous_submissions < pow_config.target_submissions_per_week / 2 {
+            core::cmp::max(previous_difficulty - 1, MIN_POW_DIFFICULTY)
+        } else {
+            previous_difficulty
+        }
+    }
+
+    #[view(getWorkVerificationMode)]
+    #[storage_mapper("workVerificationMode")]
+    fn work_verification_mode(&self) -> SingleValueMapper<WorkVerificationMode>;
+
+    #[view(getPowConfig)]
+    #[storage_mapper("powConfig")]
+    fn pow_config(&self) -> SingleValueMapper<PowConfig>;
+
+    #[storage_mapper("powDifficultyForWeek")]
+    fn pow_difficulty_for_week(&self, week: Week) -> SingleValueMapper<Difficulty>;
+
+    #[view(getSubmissionsForWeek)]
+    #[storage_mapper("submissionsForWeek")]
+    fn submissions_for_week(&self, week: Week) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,182 @@
+use crate::{
+    leaderboard::LeaderboardEntry,
+    league::Division,
+    rewards::TOP_LEADERBOARD_USERS_FOR_PRIZES,
+    week_timekeeping::{Week, INVALID_WEEK_ERR_MSG},
+};
+use multiversx_sc::api::KECCAK256_RESULT_LEN;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type LeaderboardHash<M> = ManagedByteArray<M, KECCAK256_RESULT_LEN>;
+
+/// What is left of a week once its storage is pruned
+/// The hash commits to the full leaderboard: starting from 32 zero bytes,
+/// hash = keccak256(hash ++ top_encoded_entry) for each entry, from the last to the first
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct WeekSummary<M: ManagedTypeApi> {
+    pub winners: ManagedVec<M, LeaderboardEntry<M>>,
+    pub nr_participants: usize,
+    pub total_top_work: BigUint<M>,
+    pub total_rewards: BigUint<M>,
+    pub rewards_paid: BigUint<M>,
+    pub leaderboard_hash: LeaderboardHash<M>,
+}
+
+#[multiversx_sc::module]
+pub trait PruningModule:
+    crate::rewards::RewardsModule
+    + crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::storage_version::StorageVersionModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Anyone may prune a week that is fully claimed or past the claim window
+    /// At most max_entries leaderboard entries are removed per call,
+    /// the guild leaderboard's entries being removed after the users'
+    /// Returns true once the week is fully pruned
+    #[endpoint(pruneWeek)]
+    fn prune_week(&self, week: Week, max_entries: usize) -> bool {
+        require!(week > 0, INVALID_WEEK_ERR_MSG);
+        require!(max_entries > 0, "Invalid number of entries");
+        self.require_storage_migrated();
+        self.update_league_week(self.get_current_week());
+
+        let summary_mapper = self.week_summary(week);
+        let mut leaderboard_mapper = self.leaderboard(week);
+        let mut guild_leaderboard_mapper = self.guild_leaderboard(week);
+        if summary_mapper.is_empty() {
+            self.require_week_prunable(week);
+            summary_mapper.set(self.start_pruning_week(week));
+        } else {
+            require!(
+                !leaderboard_mapper.is_empty() || !guild_leaderboard_mapper.is_empty(),
+                "Week already pruned"
+            );
+        }
+
+        let mut summary = summary_mapper.get();
+        let mut nr_pruned_entries = 0;
+        while nr_pruned_entries < max_entries && !leaderboard_mapper.is_empty() {
+            let last_index = leaderboard_mapper.len();
+            let entry = leaderboard_mapper.get(last_index);
+            leaderboard_mapper.swap_remove(last_index);
+            self.user_index_in_leaderboard(entry.user_id, week).clear();
+            self.user_claimed_for_week(entry.user_id, week).clear();
+            self.prune_division_entry(entry.user_id, week);
+            self.prune_guild_member_entry(entry.user_id, week);
+
+            let mut hash_input = summary.leaderboard_hash.as_managed_buffer().clone();
+            let _ = entry.top_encode(&mut hash_input);
+            summary.leaderboard_hash = self.crypto().keccak256(hash_input);
+
+            nr_pruned_entries += 1;
+        }
+
+        while nr_pruned_entries < max_entries && !guild_leaderboard_mapper.is_empty() {
+            let last_index = guild_leaderboard_mapper.len();
+            let guild_entry = guild_leaderboard_mapper.get(last_index);
+            guild_leaderboard_mapper.swap_remove(last_index);
+            self.guild_index_in_leaderboard(guild_entry.user_id, week)
+                .clear();
+
+            nr_pruned_entries += 1;
+        }
+
+        let is_pruned = leaderboard_mapper.is_empty() && guild_leaderboard_mapper.is_empty();
+        if is_pruned {
+            self.total_rewards_week(week).clear();
+            self.total_work_for_week(week).clear();
+            self.rewards_paid_for_week(week).clear();
+            self.nr_claims_for_week(week).clear();
+            self.legacy_user_claimed_for_week(week).clear();
+        }
+        summary_mapper.set(summary);
+
+        is_pruned
+    }
+
+    fn require_week_prunable(&self, week: Week) {
+        let current_week = self.get_current_week();
+        require!(week < current_week, "Week not over yet");
+
+        let is_past_claim_window = week < self.get_first_claimable_week(current_week);
+        let is_fully_claimed = self.developer_rewards_claimed_for_week(week).get()
+            && self.nr_claims_for_week(week).get() == self.get_nr_eligible_users(week)
+            && self.guild_rewards_week(week).is_empty();
+        require!(
+            is_past_claim_window || is_fully_claimed,
+            "Week still has rewards to claim"
+        );
+    }
+
+    /// Rewards not claimed by now are no longer owed,
+    /// unless already released when the last eligible user claimed
+    /// The same goes for the guild prize pool
+    /// In league weeks, the winners are the top users of each division, from bronze to gold
+    fn start_pruning_week(&self, week: Week) -> WeekSummary<Self::Api> {
+        let leaderboard_mapper = self.leaderboard(week);
+        let nr_eligible_users = self.get_nr_eligible_users(week);
+        let mut winners = ManagedVec::new();
+        if self.is_league_week(week) {
+            for division in Division::ALL {
+                let division_leaderboard_mapper = self.division_leaderboard(division, week);
+                let last_entry_index = core::cmp::min(
+                    division_leaderboard_mapper.len(),
+                    TOP_LEADERBOARD_USERS_FOR_PRIZES,
+                );
+                for i in 1..=last_entry_index {
+                    winners.push(division_leaderboard_mapper.get_unchecked(i));
+                }
+            }
+        } else {
+            for i in 1..=nr_eligible_users {
+                winners.push(leaderboard_mapper.get_unchecked(i));
+            }
+        }
+
+        let mut total_top_work = BigUint::zero();
+        for winner in winners.iter() {
+            total_top_work += winner.score;
+        }
+
+        let total_rewards = self.total_rewards_week(week).get();
+        let rewards_paid = self.rewards_paid_for_week(week).get();
+        let are_leftovers_released =
+            nr_eligible_users > 0 && self.nr_claims_for_week(week).get() == nr_eligible_users;
+        if !are_leftovers_released && total_rewards > rewards_paid {
+            self.decrease_outstanding_rewards(&(&total_rewards - &rewards_paid));
+        }
+
+        let guild_rewards = self.guild_rewards_week(week).take();
+        let guild_rewards_paid = self.guild_rewards_paid_for_week(week).take();
+        if guild_rewards > guild_rewards_paid {
+            self.decrease_outstanding_rewards(&(guild_rewards - guild_rewards_paid));
+        }
+
+        WeekSummary {
+            winners,
+            nr_participants: leaderboard_mapper.len(),
+            total_top_work,
+            total_rewards,
+            rewards_paid,
+            leaderboard_hash: ManagedByteArray::new_from_bytes(&[0u8; KECCAK256_RESULT_LEN]),
+        }
+    }
+
+    #[view(getWeekSummary)]
+    #[storage_mapper("weekSummary")]
+    fn week_summary(&self, week: Week) -> SingleValueMapper<WeekSummary<Self::Api>>;
+}
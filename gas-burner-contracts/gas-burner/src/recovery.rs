@@ -0,0 +1,90 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait RecoveryModule:
+    crate::rewards::RewardsModule
+    + crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::storage_version::StorageVersionModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Withdraws funds sent by mistake or left over from rounding,
+    /// never more than what is not owed to users
+    /// EGLD is only recoverable once the storage is migrated,
+    /// as the rewards owed for older weeks are only tracked from then on
+    #[only_owner]
+    #[endpoint(recoverFunds)]
+    fn recover_funds(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+        destination: ManagedAddress,
+    ) {
+        require!(amount > 0, "Invalid amount");
+        if token_id.is_egld() {
+            self.require_storage_migrated();
+        }
+        require!(
+            amount <= self.get_recoverable_balance(token_id.clone(), token_nonce),
+            "Amount exceeds the recoverable balance"
+        );
+
+        self.send()
+            .direct(&destination, &token_id, token_nonce, &amount);
+        self.funds_recovered_event(&token_id, token_nonce, &destination, &amount);
+    }
+
+    /// Only EGLD rewards, including the staking pool's and the boost payments not split yet,
+    /// and staked GAS are owed to users,
+    /// any other ESDT held by the SC is recoverable
+    #[view(getRecoverableBalance)]
+    fn get_recoverable_balance(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(&token_id, token_nonce);
+        let owed_amount = if token_id.is_egld() {
+            self.outstanding_rewards().get()
+                + self.staking_rewards_outstanding().get()
+                + self.pending_boost_rewards().get()
+        } else if self.is_staked_token(&token_id, token_nonce) {
+            self.total_staked().get()
+        } else {
+            return balance;
+        };
+
+        if balance > owed_amount {
+            balance - owed_amount
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    fn is_staked_token(&self, token_id: &EgldOrEsdtTokenIdentifier, token_nonce: u64) -> bool {
+        let token_mapper = self.token();
+        token_nonce == 0
+            && token_mapper.get_token_state().is_set()
+            && token_id == token_mapper.get_token_id_ref()
+    }
+
+    #[event("fundsRecovered")]
+    fn funds_recovered_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] destination: &ManagedAddress,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,190 @@
+use crate::{staking_pool::MAX_PERCENTAGE, week_timekeeping::Week};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum ReferralRewardKind {
+    /// Minted like work rewards, following the mint mode
+    Gas,
+    /// Added to the referrer's score for the current week
+    Score,
+}
+
+/// The referrer earns a percentage, out of MAX_PERCENTAGE, of each referee work's
+/// minted GAS or score increase, for duration_weeks starting with the referral's week
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct ReferralConfig {
+    pub reward_kind: ReferralRewardKind,
+    pub percentage: u32,
+    pub duration_weeks: Week,
+    pub max_referees: usize,
+}
+
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub struct Referral {
+    pub referrer_id: AddressId,
+    pub end_week: Week,
+}
+
+/// Referrals are a single level, the referrer's own referrer earns nothing from its referees
+#[multiversx_sc::module]
+pub trait ReferralModule:
+    crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::locked_token::LockedTokenModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Changes apply to kickbacks paid afterwards,
+    /// except for the duration, which is fixed when the referral is registered
+    #[only_owner]
+    #[endpoint(setReferralConfig)]
+    fn set_referral_config(&self, config: ReferralConfig) {
+        require!(
+            config.percentage > 0 && config.percentage <= MAX_PERCENTAGE,
+            "Invalid percentage"
+        );
+        require!(config.duration_weeks > 0, "Invalid duration");
+        require!(config.max_referees > 0, "Invalid max referees");
+
+        self.referral_config().set(config);
+    }
+
+    /// Stops the kickbacks of an abusive referral
+    #[only_owner]
+    #[endpoint(removeReferral)]
+    fn remove_referral(&self, user: ManagedAddress) {
+        let user_id = self.user_id().get_id_non_zero(&user);
+        let referral_mapper = self.referral(user_id);
+        require!(!referral_mapper.is_empty(), "No referrer");
+
+        let referral = referral_mapper.take();
+        let _ = self.referees(referral.referrer_id).swap_remove(&user_id);
+    }
+
+    #[view(getReferrer)]
+    fn get_referrer(&self, user: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 || self.referral(user_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let referral = self.referral(user_id).get();
+        self.user_id().get_address(referral.referrer_id).into()
+    }
+
+    #[view(getReferees)]
+    fn get_referees(&self, referrer: ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let mut referees = MultiValueEncoded::new();
+        let referrer_id = self.user_id().get_id(&referrer);
+        if referrer_id == 0 {
+            return referees;
+        }
+
+        for referee_id in self.referees(referrer_id).iter() {
+            if let Some(referee) = self.user_id().get_address(referee_id) {
+                referees.push(referee);
+            }
+        }
+
+        referees
+    }
+
+    /// The user must not have worked yet, the referrer must have worked before
+    fn register_referrer(&self, user: &ManagedAddress, referrer: &ManagedAddress) {
+        let config_mapper = self.referral_config();
+        require!(!config_mapper.is_empty(), "Referrals not enabled");
+
+        let user_id = self.user_id().get_id_or_insert(user);
+        require!(
+            !self.has_worked(user_id),
+            "Referrer can only be set on the first work"
+        );
+
+        let referrer_id = self.user_id().get_id(referrer);
+        require!(
+            referrer_id != 0 && self.has_worked(referrer_id),
+            "Unknown referrer"
+        );
+
+        let config = config_mapper.get();
+        let mut referees_mapper = self.referees(referrer_id);
+        require!(
+            referees_mapper.len() < config.max_referees,
+            "Referrer has too many referees"
+        );
+
+        let _ = referees_mapper.insert(user_id);
+        self.referral(user_id).set(Referral {
+            referrer_id,
+            end_week: self.get_current_week() + config.duration_weeks,
+        });
+    }
+
+    fn pay_referral_kickback(
+        &self,
+        user: &ManagedAddress,
+        minted_amount: &BigUint,
+        score_increase: &BigUint,
+    ) {
+        let user_id = self.user_id().get_id(user);
+        let referral_mapper = self.referral(user_id);
+        if referral_mapper.is_empty() {
+            return;
+        }
+
+        let referral = referral_mapper.get();
+        if self.get_current_week() >= referral.end_week {
+            return;
+        }
+
+        let config = self.referral_config().get();
+        match config.reward_kind {
+            ReferralRewardKind::Gas => {
+                let kickback = minted_amount * config.percentage / MAX_PERCENTAGE;
+                if kickback > 0 {
+                    let referrer = self
+                        .user_id()
+                        .get_address(referral.referrer_id)
+                        .unwrap_or_else(|| sc_panic!("Unknown referrer"));
+                    let _ = self.mint_rewards(&referrer, kickback);
+                }
+            }
+            ReferralRewardKind::Score => {
+                let kickback = score_increase * config.percentage / MAX_PERCENTAGE;
+                if kickback > 0 {
+                    self.credit_leaderboard_score(referral.referrer_id, &kickback);
+                    self.increase_team_entries(referral.referrer_id, 0, &kickback);
+                }
+            }
+        }
+    }
+
+    /// The guild and division totals follow every score increase of their members
+    fn increase_team_entries(&self, user_id: AddressId, gas_amount: u64, score_increase: &BigUint) {
+        self.increase_guild_entry(user_id, gas_amount, score_increase);
+        self.increase_division_entry(user_id, gas_amount, score_increase);
+    }
+
+    #[view(getReferralConfig)]
+    #[storage_mapper("referralConfig")]
+    fn referral_config(&self) -> SingleValueMapper<ReferralConfig>;
+
+    #[storage_mapper("referral")]
+    fn referral(&self, user_id: AddressId) -> SingleValueMapper<Referral>;
+
+    #[storage_mapper("referees")]
+    fn referees(&self, referrer_id: AddressId) -> UnorderedSetMapper<AddressId>;
+}
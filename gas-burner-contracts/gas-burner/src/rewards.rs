@@ -1,74 +1,201 @@
-use crate::week_timekeeping::{Week, INVALID_WEEK_ERR_MSG};
+use crate::{
+    guild::TOP_GUILDS_FOR_PRIZES,
+    leaderboard::LeaderboardKind,
+    league::Division,
+    owner_sc_proxy::OwnerScProxy,
+    pause_flags::PauseFlag,
+    staking_pool::MAX_PERCENTAGE,
+    week_timekeeping::{Week, FIRST_WEEK, INVALID_WEEK_ERR_MSG},
+};
 
 multiversx_sc::imports!();
 
 pub const TOP_LEADERBOARD_USERS_FOR_PRIZES: usize = 10;
+/// Number of weeks after a week ends during which its rewards can be claimed,
+/// until the owner sets another window
+pub const DEFAULT_CLAIM_WINDOW_WEEKS: Week = 12;
 
 #[multiversx_sc::module]
 pub trait RewardsModule:
     crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::token::TokenModule
     + crate::week_timekeeping::WeekTimekeepingModule
     + crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::storage_version::StorageVersionModule
+    + multiversx_sc_modules::pause::PauseModule
 {
+    /// Weeks past the claim window are skipped
+    /// While dev rewards collection is paused, the previous week is only claimable
+    /// if its rewards were already collected
     #[endpoint(claimRewards)]
     fn claim_rewards(&self, start_week: Week) -> BigUint {
-        require!(start_week > 0, INVALID_WEEK_ERR_MSG);
+        let (start_week, end_week) = self.get_claimable_weeks(start_week);
 
-        let current_week = self.get_current_week();
-        require!(start_week < current_week, "Invalid start week");
+        let caller = self.blockchain().get_caller();
+        let user_id = self.user_id().get_id_non_zero(&caller);
+        let total_rewards = self.claim_user_rewards(start_week, end_week, user_id);
+        self.send().direct_non_zero_egld(&caller, &total_rewards);
 
-        let previous_week = current_week - 1;
-        self.claim_developer_rewards(previous_week);
+        total_rewards
+    }
+
+    /// Members of the top guilds of a week split its guild prize pool,
+    /// according to their contribution to their guild's score
+    /// The same claim window and dev rewards collection rules as claimRewards apply
+    #[endpoint(claimGuildRewards)]
+    fn claim_guild_rewards(&self, start_week: Week) -> BigUint {
+        let (start_week, end_week) = self.get_claimable_weeks(start_week);
 
         let caller = self.blockchain().get_caller();
         let user_id = self.user_id().get_id_non_zero(&caller);
-        let total_rewards = self.claim_user_rewards(start_week, current_week, user_id);
+        let mut total_rewards = BigUint::zero();
+        for week in start_week..end_week {
+            let guild_id = self.user_guild_for_week(user_id, week).get();
+            let guild_index_in_lb = self.guild_index_in_leaderboard(guild_id, week).get();
+            if guild_id == 0 || guild_index_in_lb == 0 || guild_index_in_lb > TOP_GUILDS_FOR_PRIZES
+            {
+                continue;
+            }
+
+            let user_claimed_mapper = self.user_claimed_guild_rewards_for_week(user_id, week);
+            if user_claimed_mapper.get() {
+                continue;
+            }
+
+            let member_score = self.guild_member_score(user_id, week).get();
+            let user_rewards = self.guild_rewards_week(week).get() * member_score
+                / self.calculate_top_total_guild_score_for_week(week);
+            self.guild_rewards_paid_for_week(week)
+                .update(|paid| *paid += &user_rewards);
+            self.decrease_outstanding_rewards(&user_rewards);
+            total_rewards += user_rewards;
+
+            user_claimed_mapper.set(true);
+        }
+
         self.send().direct_non_zero_egld(&caller, &total_rewards);
 
         total_rewards
     }
 
-    fn claim_developer_rewards(&self, previous_week: Week) {
+    /// A shorter window only counts from the current week,
+    /// so that no week becomes unclaimable at once
+    #[only_owner]
+    #[endpoint(setClaimWindow)]
+    fn set_claim_window(&self, claim_window_weeks: Week) {
+        require!(claim_window_weeks > 0, "Invalid claim window");
+
+        let claim_window_mapper = self.claim_window_weeks();
+        if claim_window_weeks < claim_window_mapper.get() {
+            self.claim_window_start_week().set(self.get_current_week());
+        }
+        claim_window_mapper.set(claim_window_weeks);
+    }
+
+    /// Returns the range of weeks to claim for, with the end week excluded
+    fn get_claimable_weeks(&self, start_week: Week) -> (Week, Week) {
+        self.require_flag_not_paused(PauseFlag::Claims);
+        self.require_storage_migrated();
+        require!(start_week > 0, INVALID_WEEK_ERR_MSG);
+
+        let current_week = self.get_current_week();
+        require!(start_week < current_week, "Invalid start week");
+
+        let start_week = core::cmp::max(start_week, self.get_first_claimable_week(current_week));
+        let previous_week = current_week - 1;
+        let end_week = if self.claim_developer_rewards(previous_week) {
+            current_week
+        } else {
+            previous_week
+        };
+
+        (start_week, end_week)
+    }
+
+    /// Returns whether the week's dev rewards are collected
+    /// The EGLD paid for boosts since the last collection is split along with them
+    fn claim_developer_rewards(&self, previous_week: Week) -> bool {
         let developer_rewards_claimed_for_week_mapper =
             self.developer_rewards_claimed_for_week(previous_week);
         if developer_rewards_claimed_for_week_mapper.get() {
-            return;
+            return true;
+        }
+        if !self.is_flag_active(PauseFlag::DevRewardsCollection) {
+            return false;
         }
 
         let owner_sc_address = self.owner_sc().get();
-        let dev_rewards: BigUint = self
-            .owner_sc_proxy(owner_sc_address)
+        let dev_rewards = self
+            .tx()
+            .to(&owner_sc_address)
+            .typed(OwnerScProxy)
             .claim_dev_rewards()
-            .execute_on_dest_context();
-        self.total_rewards_week(previous_week).set(dev_rewards);
+            .returns(ReturnsResult)
+            .sync_call();
+        let rewards = dev_rewards + self.pending_boost_rewards().take();
+        let staking_pool_share = self.distribute_staking_pool_share(&rewards);
+        let guild_pool_share =
+            self.distribute_guild_prize_pool_share(previous_week, &rewards, &staking_pool_share);
+        let total_rewards = rewards - staking_pool_share - &guild_pool_share;
+        self.outstanding_rewards()
+            .update(|outstanding| *outstanding += &total_rewards + &guild_pool_share);
+        self.total_rewards_week(previous_week)
+            .update(|week_rewards| *week_rewards += total_rewards);
 
         developer_rewards_claimed_for_week_mapper.set(true);
+
+        true
     }
 
-    fn claim_user_rewards(
-        &self,
-        start_week: Week,
-        current_week: Week,
-        user_id: AddressId,
-    ) -> BigUint {
+    fn claim_user_rewards(&self, start_week: Week, end_week: Week, user_id: AddressId) -> BigUint {
         let mut total_rewards = BigUint::zero();
-        for week in start_week..current_week {
-            let user_index_in_lb = self.user_index_in_leaderboard(user_id, week).get();
+        for week in start_week..end_week {
+            let kind = self.get_prize_leaderboard_kind(user_id, week);
+            let user_index_in_lb = self.index_in_leaderboard(kind, user_id, week).get();
             if user_index_in_lb == 0 || user_index_in_lb > TOP_LEADERBOARD_USERS_FOR_PRIZES {
                 continue;
             }
 
-            let user_claimed_for_week_mapper = self.user_claimed_for_week(week);
-            if user_claimed_for_week_mapper.get() {
+            let user_claimed_for_week_mapper = self.user_claimed_for_week(user_id, week);
+            if user_claimed_for_week_mapper.get() || self.legacy_user_claimed_for_week(week).get() {
                 continue;
             }
 
-            let total_work_for_week = self.calculate_top_total_user_work_for_week(week);
             let total_rewards_for_week = self.total_rewards_week(week).get();
+            let (prize_pool, total_work_for_week) = match kind {
+                LeaderboardKind::Division(division) => {
+                    let pool_percentage = self
+                        .league_config_for_week(week)
+                        .get()
+                        .get_pool_percentage(division);
+                    (
+                        &total_rewards_for_week * pool_percentage / MAX_PERCENTAGE,
+                        self.calculate_top_total_division_score_for_week(
+                            division,
+                            week,
+                            TOP_LEADERBOARD_USERS_FOR_PRIZES,
+                        ),
+                    )
+                }
+                _ => (
+                    total_rewards_for_week.clone(),
+                    self.calculate_top_total_user_work_for_week(week),
+                ),
+            };
 
-            let user_entry = self.leaderboard(week).get_unchecked(user_index_in_lb);
-            let user_rewards =
-                total_rewards_for_week * user_entry.work_amount / total_work_for_week;
+            let user_entry = self
+                .leaderboard_for_kind(kind, week)
+                .get_unchecked(user_index_in_lb);
+            let user_rewards = prize_pool * &user_entry.score / total_work_for_week;
+            self.record_rewards_paid(week, &total_rewards_for_week, &user_rewards);
             total_rewards += user_rewards;
 
             user_claimed_for_week_mapper.set(true);
@@ -77,6 +204,69 @@ pub trait RewardsModule:
         total_rewards
     }
 
+    /// Once every eligible user claimed for the week, the rounding leftovers are no longer owed
+    fn record_rewards_paid(
+        &self,
+        week: Week,
+        total_rewards_for_week: &BigUint,
+        user_rewards: &BigUint,
+    ) {
+        let rewards_paid = self.rewards_paid_for_week(week).update(|paid| {
+            *paid += user_rewards;
+            paid.clone()
+        });
+        self.decrease_outstanding_rewards(user_rewards);
+
+        let nr_claims = self.nr_claims_for_week(week).update(|nr_claims| {
+            *nr_claims += 1;
+            *nr_claims
+        });
+        if nr_claims == self.get_nr_eligible_users(week) && *total_rewards_for_week > rewards_paid {
+            self.decrease_outstanding_rewards(&(total_rewards_for_week - &rewards_paid));
+        }
+    }
+
+    /// Weeks before the window start week count their claim window from it
+    fn get_first_claimable_week(&self, current_week: Week) -> Week {
+        let claim_window_weeks = self.claim_window_weeks().get();
+        if current_week > self.claim_window_start_week().get() + claim_window_weeks {
+            current_week - claim_window_weeks
+        } else {
+            FIRST_WEEK
+        }
+    }
+
+    /// In league weeks, the top users of each division are eligible
+    fn get_nr_eligible_users(&self, week: Week) -> usize {
+        if !self.is_league_week(week) {
+            return core::cmp::min(
+                self.leaderboard(week).len(),
+                TOP_LEADERBOARD_USERS_FOR_PRIZES,
+            );
+        }
+
+        let mut nr_eligible_users = 0;
+        for division in Division::ALL {
+            nr_eligible_users += core::cmp::min(
+                self.division_leaderboard(division, week).len(),
+                TOP_LEADERBOARD_USERS_FOR_PRIZES,
+            );
+        }
+
+        nr_eligible_users
+    }
+
+    /// Saturates, so the liabilities bookkeeping can never block a claim
+    fn decrease_outstanding_rewards(&self, amount: &BigUint) {
+        self.outstanding_rewards().update(|outstanding| {
+            if *outstanding > *amount {
+                *outstanding -= amount;
+            } else {
+                *outstanding = BigUint::zero();
+            }
+        });
+    }
+
     fn calculate_top_total_user_work_for_week(&self, week: Week) -> BigUint {
         let total_work_mapper = self.total_work_for_week(week);
         if !total_work_mapper.is_empty() {
@@ -89,7 +279,7 @@ pub trait RewardsModule:
         let mut total_work = BigUint::zero();
         for i in 1..=last_entry_index {
             let lb_entry = leaderboard_mapper.get_unchecked(i);
-            total_work += lb_entry.work_amount;
+            total_work += lb_entry.score;
         }
 
         total_work_mapper.set(&total_work);
@@ -100,18 +290,46 @@ pub trait RewardsModule:
     #[storage_mapper("devRewardsClaimedForWeek")]
     fn developer_rewards_claimed_for_week(&self, week: Week) -> SingleValueMapper<bool>;
 
+    /// Dev rewards collected for the week, without the staking and guild pool shares
     #[storage_mapper("totalRewardsWeek")]
     fn total_rewards_week(&self, week: Week) -> SingleValueMapper<BigUint>;
 
+    /// EGLD paid for boosts, not split with the dev rewards yet
+    #[view(getPendingBoostRewards)]
+    #[storage_mapper("pendingBoostRewards")]
+    fn pending_boost_rewards(&self) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("totalWorkForWeek")]
     fn total_work_for_week(&self, week: Week) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("rewardsPaidForWeek")]
+    fn rewards_paid_for_week(&self, week: Week) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("nrClaimsForWeek")]
+    fn nr_claims_for_week(&self, week: Week) -> SingleValueMapper<usize>;
+
+    /// EGLD rewards owed to users, over all weeks, including the guild prize pools
+    #[view(getOutstandingRewards)]
+    #[storage_mapper("outstandingRewards")]
+    fn outstanding_rewards(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userClaimedRewardsForWeek")]
+    fn user_claimed_for_week(&self, user_id: AddressId, week: Week) -> SingleValueMapper<bool>;
+
+    /// Claimed flags of storage version 1, kept per week only
+    /// Set for weeks still waiting for resolveLegacyClaim
+    #[view(isLegacyClaimUnresolved)]
     #[storage_mapper("userClaimedForWeek")]
-    fn user_claimed_for_week(&self, week: Week) -> SingleValueMapper<bool>;
+    fn legacy_user_claimed_for_week(&self, week: Week) -> SingleValueMapper<bool>;
+
+    #[view(getClaimWindowWeeks)]
+    #[storage_mapper("claimWindowWeeks")]
+    fn claim_window_weeks(&self) -> SingleValueMapper<Week>;
+
+    #[view(getClaimWindowStartWeek)]
+    #[storage_mapper("claimWindowStartWeek")]
+    fn claim_window_start_week(&self) -> SingleValueMapper<Week>;
 
     #[storage_mapper("ownerSc")]
     fn owner_sc(&self) -> SingleValueMapper<ManagedAddress>;
-
-    #[proxy]
-    fn owner_sc_proxy(&self, sc_address: ManagedAddress) -> owner_sc::Proxy<Self::Api>;
 }
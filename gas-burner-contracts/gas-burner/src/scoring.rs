@@ -0,0 +1,102 @@
+use crate::{
+    staking::{Multiplier, BASE_MULTIPLIER},
+    week_timekeeping::Week,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_SCORING_SEGMENTS: usize = 10;
+
+/// Gas above from_gas, up to the next segment, counts at the given rate
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug,
+)]
+pub struct ScoringSegment<M: ManagedTypeApi> {
+    pub from_gas: BigUint<M>,
+    pub rate: Multiplier,
+}
+
+/// Maps a user's raw gas for the week to their leaderboard score
+/// In piecewise mode, gas below the first segment counts fully
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub enum ScoringCurve<M: ManagedTypeApi> {
+    Linear,
+    SquareRoot,
+    Piecewise(ManagedVec<M, ScoringSegment<M>>),
+}
+
+#[multiversx_sc::module]
+pub trait ScoringModule {
+    /// Applies from the next week the curve is snapshotted for,
+    /// which is the current week if no work was done in it yet
+    #[only_owner]
+    #[endpoint(setScoringCurve)]
+    fn set_scoring_curve(&self, curve: ScoringCurve<Self::Api>) {
+        if let ScoringCurve::Piecewise(segments) = &curve {
+            require!(
+                !segments.is_empty() && segments.len() <= MAX_SCORING_SEGMENTS,
+                "Invalid number of segments"
+            );
+
+            let mut prev_from_gas = BigUint::zero();
+            for segment in segments.iter() {
+                require!(
+                    segment.from_gas > prev_from_gas,
+                    "Segments not sorted by gas"
+                );
+                require!(segment.rate <= BASE_MULTIPLIER, "Invalid rate");
+
+                prev_from_gas = segment.from_gas;
+            }
+        }
+
+        self.scoring_curve().set(curve);
+    }
+
+    fn get_or_snapshot_scoring_curve(&self, week: Week) -> ScoringCurve<Self::Api> {
+        let week_curve_mapper = self.scoring_curve_for_week(week);
+        if week_curve_mapper.is_empty() {
+            let curve = self.scoring_curve().get();
+            week_curve_mapper.set(&curve);
+
+            return curve;
+        }
+
+        week_curve_mapper.get()
+    }
+
+    fn compute_score(&self, curve: &ScoringCurve<Self::Api>, raw_gas: &BigUint) -> BigUint {
+        match curve {
+            ScoringCurve::Linear => raw_gas.clone(),
+            ScoringCurve::SquareRoot => raw_gas.sqrt(),
+            ScoringCurve::Piecewise(segments) => {
+                let mut score = BigUint::zero();
+                let mut segment_start = BigUint::zero();
+                let mut rate = BASE_MULTIPLIER;
+                for segment in segments.iter() {
+                    if *raw_gas <= segment.from_gas {
+                        break;
+                    }
+
+                    score += (&segment.from_gas - &segment_start) * rate / BASE_MULTIPLIER;
+                    segment_start = segment.from_gas;
+                    rate = segment.rate;
+                }
+
+                score + (raw_gas - &segment_start) * rate / BASE_MULTIPLIER
+            }
+        }
+    }
+
+    #[view(getScoringCurve)]
+    #[storage_mapper("scoringCurve")]
+    fn scoring_curve(&self) -> SingleValueMapper<ScoringCurve<Self::Api>>;
+
+    /// Curve applied to the week, set by the week's first work
+    #[view(getScoringCurveForWeek)]
+    #[storage_mapper("scoringCurveForWeek")]
+    fn scoring_curve_for_week(&self, week: Week) -> SingleValueMapper<ScoringCurve<Self::Api>>;
+}
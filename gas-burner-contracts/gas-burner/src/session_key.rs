@@ -0,0 +1,115 @@
+use crate::{
+    pause_flags::PauseFlag,
+    signed_message::{encode_session_key_message_body, SignedEndpoint},
+    signers::SignerSignature,
+    week_timekeeping::Epoch,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct SessionKey<M: ManagedTypeApi> {
+    pub delegate: ManagedAddress<M>,
+    pub expiry_epoch: Epoch,
+    pub remaining_gas: u64,
+}
+
+#[multiversx_sc::module]
+pub trait SessionKeyModule:
+    crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The delegate may call workFor on behalf of the caller until the expiry epoch,
+    /// using at most max_gas in total. Registering again replaces the previous session key
+    /// In threshold mode, signers must attest the registration, see the signed_message module
+    #[endpoint(registerSessionKey)]
+    fn register_session_key(
+        &self,
+        delegate: ManagedAddress,
+        expiry_epoch: Epoch,
+        max_gas: u64,
+        attestations: MultiValueEncoded<SignerSignature<Self::Api>>,
+    ) {
+        self.require_flag_not_paused(PauseFlag::Registration);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !delegate.is_zero() && delegate != caller,
+            "Invalid delegate address"
+        );
+        require!(
+            expiry_epoch > self.blockchain().get_block_epoch(),
+            "Invalid expiry epoch"
+        );
+        require!(max_gas > 0, "Invalid gas allowance");
+
+        let mut message_body = ManagedBuffer::new();
+        encode_session_key_message_body(
+            &mut message_body,
+            &caller,
+            &delegate,
+            expiry_epoch,
+            max_gas,
+        );
+        self.check_threshold_attestation(
+            SignedEndpoint::RegisterSessionKey,
+            &message_body,
+            &attestations.to_vec(),
+        );
+
+        let user_id = self.user_id().get_id_or_insert(&caller);
+        self.session_key(user_id).set(SessionKey {
+            delegate,
+            expiry_epoch,
+            remaining_gas: max_gas,
+        });
+    }
+
+    #[endpoint(revokeSessionKey)]
+    fn revoke_session_key(&self) {
+        let caller = self.blockchain().get_caller();
+        let user_id = self.user_id().get_id_non_zero(&caller);
+        let session_key_mapper = self.session_key(user_id);
+        require!(!session_key_mapper.is_empty(), "No session key registered");
+
+        session_key_mapper.clear();
+    }
+
+    fn use_session_key(&self, user: &ManagedAddress, delegate: &ManagedAddress, gas_amount: u64) {
+        let user_id = self.user_id().get_id_non_zero(user);
+        let session_key_mapper = self.session_key(user_id);
+        require!(!session_key_mapper.is_empty(), "No session key registered");
+
+        let mut session_key = session_key_mapper.get();
+        require!(&session_key.delegate == delegate, "Not the user's delegate");
+        require!(
+            self.blockchain().get_block_epoch() < session_key.expiry_epoch,
+            "Session key expired"
+        );
+        require!(
+            gas_amount <= session_key.remaining_gas,
+            "Gas allowance exceeded"
+        );
+
+        session_key.remaining_gas -= gas_amount;
+        session_key_mapper.set(session_key);
+    }
+
+    #[view(getSessionKey)]
+    fn get_session_key(&self, user: ManagedAddress) -> OptionalValue<SessionKey<Self::Api>> {
+        let user_id = self.user_id().get_id(&user);
+        let session_key_mapper = self.session_key(user_id);
+        if user_id == 0 || session_key_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(session_key_mapper.get())
+    }
+
+    #[storage_mapper("sessionKey")]
+    fn session_key(&self, user_id: AddressId) -> SingleValueMapper<SessionKey<Self::Api>>;
+}
@@ -1,38 +1,124 @@
+use crate::{
+    signed_message::{encode_work_message_body, SignedEndpoint},
+    signers::{KeyId, SignerSignature, ED25519_SIGNATURE_LEN},
+};
+
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 pub type Nonce = u64;
 
+pub const NONCE_WINDOW_SIZE: Nonce = 64;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct WorkSignature<M: ManagedTypeApi> {
+    pub key_id: KeyId,
+    pub expiry_block: u64,
+    pub opt_work_kind: Option<ManagedBuffer<M>>,
+    pub signature: ManagedByteArray<M, ED25519_SIGNATURE_LEN>,
+}
+
 #[multiversx_sc::module]
-pub trait SignatureModule {
+pub trait SignatureModule: crate::signers::SignersModule {
+    /// Returns the lowest unused nonce, which is also the start of the user's nonce window
     #[view(getUserNonce)]
     fn get_user_nonce(&self, user: ManagedAddress) -> Nonce {
         let user_id = self.user_id().get_id(&user);
         self.user_nonce(user_id).get()
     }
 
-    fn check_worker_signature(&self, user: &ManagedAddress, signature: &ManagedBuffer) {
+    /// Nonces in the user's window that were already used, out of order
+    #[view(getUsedNoncesInWindow)]
+    fn get_used_nonces_in_window(&self, user: ManagedAddress) -> MultiValueEncoded<Nonce> {
+        let mut used_nonces = MultiValueEncoded::new();
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 {
+            return used_nonces;
+        }
+
+        let window_start = self.user_nonce(user_id).get();
+        let used_nonces_bitmap = self.used_nonces_bitmap(user_id).get();
+        for offset in 0..NONCE_WINDOW_SIZE {
+            if used_nonces_bitmap & (1 << offset) != 0 {
+                used_nonces.push(window_start + offset);
+            }
+        }
+
+        used_nonces
+    }
+
+    /// The proof is an encoded WorkSignature
+    /// The signed message layout is described in the signed_message module
+    fn check_worker_signature(
+        &self,
+        user: &ManagedAddress,
+        endpoint: SignedEndpoint,
+        user_nonce: Nonce,
+        proof: &ManagedBuffer,
+    ) {
         if cfg!(debug_assertions) {
             return;
         }
 
-        let user_nonce = self.get_and_increment_user_nonce(user);
-        let mut encoded_nonce = ManagedBuffer::new();
-        let _ = user_nonce.top_encode(&mut encoded_nonce);
+        let work_signature = WorkSignature::top_decode(proof.clone())
+            .unwrap_or_else(|_| sc_panic!("Invalid signature format"));
+        require!(
+            self.blockchain().get_block_nonce() <= work_signature.expiry_block,
+            "Signature expired"
+        );
 
-        let mut signed_message = user.as_managed_buffer().clone();
-        signed_message = signed_message.concat(encoded_nonce);
-        self.crypto()
-            .verify_ed25519(user.as_managed_buffer(), &signed_message, signature);
+        let mut message_body = ManagedBuffer::new();
+        encode_work_message_body(
+            &mut message_body,
+            user,
+            user_nonce,
+            work_signature.expiry_block,
+            &work_signature.opt_work_kind,
+        );
+
+        let signer_signature = SignerSignature {
+            key_id: work_signature.key_id,
+            signature: work_signature.signature,
+        };
+        self.verify_signer_signature(endpoint, &message_body, &signer_signature);
     }
 
-    fn get_and_increment_user_nonce(&self, user: &ManagedAddress) -> Nonce {
+    /// Any unused nonce in [window_start, window_start + NONCE_WINDOW_SIZE) is accepted
+    /// If no nonce is given, the lowest unused one is used
+    fn use_user_nonce(&self, user: &ManagedAddress, opt_nonce: OptionalValue<Nonce>) -> Nonce {
         let user_id = self.user_id().get_id_or_insert(user);
-        self.user_nonce(user_id).update(|user_nonce| {
-            let returned_nonce = *user_nonce;
-            *user_nonce += 1;
+        let window_start_mapper = self.user_nonce(user_id);
+        let used_nonces_bitmap_mapper = self.used_nonces_bitmap(user_id);
+        let window_start = window_start_mapper.get();
+        let user_nonce = match opt_nonce {
+            OptionalValue::Some(nonce) => nonce,
+            OptionalValue::None => window_start,
+        };
+        require!(user_nonce >= window_start, "Nonce already used");
+
+        let offset = user_nonce - window_start;
+        require!(offset < NONCE_WINDOW_SIZE, "Nonce outside of the window");
+
+        let mut used_nonces_bitmap = used_nonces_bitmap_mapper.get();
+        let nonce_bit = 1 << offset;
+        require!(used_nonces_bitmap & nonce_bit == 0, "Nonce already used");
+        used_nonces_bitmap |= nonce_bit;
+
+        // slide the window past the consecutive used nonces at its start
+        let window_shift = used_nonces_bitmap.trailing_ones() as Nonce;
+        used_nonces_bitmap = used_nonces_bitmap
+            .checked_shr(window_shift as u32)
+            .unwrap_or(0);
+        window_start_mapper.set(window_start + window_shift);
+        used_nonces_bitmap_mapper.set(used_nonces_bitmap);
+
+        user_nonce
+    }
 
-            returned_nonce
-        })
+    /// Every work uses a nonce, while user ids are also created by other endpoints
+    fn has_worked(&self, user_id: AddressId) -> bool {
+        self.user_nonce(user_id).get() > 0 || self.used_nonces_bitmap(user_id).get() != 0
     }
 
     #[storage_mapper("userId")]
@@ -41,6 +127,6 @@ pub trait SignatureModule {
     #[storage_mapper("userNonce")]
     fn user_nonce(&self, user_id: AddressId) -> SingleValueMapper<Nonce>;
 
-    #[storage_mapper("signer")]
-    fn signer(&self) -> SingleValueMapper<ManagedAddress>;
+    #[storage_mapper("usedNoncesBitmap")]
+    fn used_nonces_bitmap(&self, user_id: AddressId) -> SingleValueMapper<u64>;
 }
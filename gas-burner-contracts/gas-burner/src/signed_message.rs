@@ -0,0 +1,129 @@
+//! Layout of the messages signed by the authorized signers.
+//! The same encoding functions are used on-chain, with managed types,
+//! and off-chain, with plain bytes, so both always produce identical messages.
+
+use crate::{signature::Nonce, signers::KeyId, week_timekeeping::Epoch};
+use multiversx_sc::codec::NestedEncodeOutput;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub static SIGNED_MESSAGE_PREFIX: &[u8] = b"GasBurnerSignedMessage";
+pub const SIGNED_MESSAGE_VERSION: u8 = 1;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum SignedEndpoint {
+    Work,
+    WorkFor,
+    RegisterSessionKey,
+}
+
+/// prefix + version + chain_id + contract_address + endpoint + key_id
+/// The chain id is length-prefixed, the key id is 4 big-endian bytes
+pub fn encode_message_header<O, ChainId, Address>(
+    output: &mut O,
+    chain_id: &ChainId,
+    contract_address: &Address,
+    endpoint: SignedEndpoint,
+    key_id: KeyId,
+) where
+    O: NestedEncodeOutput,
+    ChainId: NestedEncode,
+    Address: NestedEncode,
+{
+    output.write(SIGNED_MESSAGE_PREFIX);
+    let _ = SIGNED_MESSAGE_VERSION.dep_encode(output);
+    let _ = chain_id.dep_encode(output);
+    let _ = contract_address.dep_encode(output);
+    let _ = endpoint.dep_encode(output);
+    let _ = key_id.dep_encode(output);
+}
+
+/// user_address + nonce + expiry_block + optional work_kind
+/// Numbers are 8 big-endian bytes
+/// The work kind is a 0/1 flag, followed by the length-prefixed bytes if present
+pub fn encode_work_message_body<O, Address, WorkKind>(
+    output: &mut O,
+    user: &Address,
+    nonce: Nonce,
+    expiry_block: u64,
+    opt_work_kind: &Option<WorkKind>,
+) where
+    O: NestedEncodeOutput,
+    Address: NestedEncode,
+    WorkKind: NestedEncode,
+{
+    let _ = user.dep_encode(output);
+    let _ = nonce.dep_encode(output);
+    let _ = expiry_block.dep_encode(output);
+    let _ = opt_work_kind.dep_encode(output);
+}
+
+/// user_address + delegate_address + expiry_epoch + max_gas
+pub fn encode_session_key_message_body<O, Address>(
+    output: &mut O,
+    user: &Address,
+    delegate: &Address,
+    expiry_epoch: Epoch,
+    max_gas: u64,
+) where
+    O: NestedEncodeOutput,
+    Address: NestedEncode,
+{
+    let _ = user.dep_encode(output);
+    let _ = delegate.dep_encode(output);
+    let _ = expiry_epoch.dep_encode(output);
+    let _ = max_gas.dep_encode(output);
+}
+
+/// Helpers for building the exact bytes a signer has to sign
+#[cfg(feature = "offchain")]
+pub mod offchain {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    pub type RawAddress = [u8; 32];
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_work_message(
+        chain_id: &[u8],
+        contract_address: &RawAddress,
+        endpoint: SignedEndpoint,
+        key_id: KeyId,
+        user: &RawAddress,
+        nonce: Nonce,
+        expiry_block: u64,
+        opt_work_kind: Option<&[u8]>,
+    ) -> Vec<u8> {
+        let mut message = Vec::new();
+        encode_message_header(&mut message, &chain_id, contract_address, endpoint, key_id);
+        encode_work_message_body(&mut message, user, nonce, expiry_block, &opt_work_kind);
+
+        message
+    }
+
+    pub fn build_session_key_message(
+        chain_id: &[u8],
+        contract_address: &RawAddress,
+        key_id: KeyId,
+        user: &RawAddress,
+        delegate: &RawAddress,
+        expiry_epoch: Epoch,
+        max_gas: u64,
+    ) -> Vec<u8> {
+        let mut message = Vec::new();
+        encode_message_header(
+            &mut message,
+            &chain_id,
+            contract_address,
+            SignedEndpoint::RegisterSessionKey,
+            key_id,
+        );
+        encode_session_key_message_body(&mut message, user, delegate, expiry_epoch, max_gas);
+
+        message
+    }
+}
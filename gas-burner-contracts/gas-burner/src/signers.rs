@@ -0,0 +1,180 @@
+use crate::signed_message::{encode_message_header, SignedEndpoint};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type KeyId = u32;
+
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Debug)]
+pub struct SignerSignature<M: ManagedTypeApi> {
+    pub key_id: KeyId,
+    pub signature: ManagedByteArray<M, ED25519_SIGNATURE_LEN>,
+}
+
+#[multiversx_sc::module]
+pub trait SignersModule {
+    #[only_owner]
+    #[endpoint(addSigner)]
+    fn add_signer(&self, signer: ManagedAddress) -> KeyId {
+        self.add_signer_common(signer)
+    }
+
+    #[only_owner]
+    #[endpoint(removeSigner)]
+    fn remove_signer(&self, key_id: KeyId) {
+        let removed = self.signers().remove(&key_id);
+        require!(removed.is_some(), "Unknown signer key id");
+
+        let signers_len = self.signers().len();
+        require!(signers_len > 0, "Cannot remove the last signer");
+        require!(
+            signers_len >= self.signature_threshold().get(),
+            "Not enough signers left for the threshold"
+        );
+    }
+
+    /// Replaces the key behind an existing key id,
+    /// so signatures from the previous key are no longer accepted
+    #[only_owner]
+    #[endpoint(rotateSigner)]
+    fn rotate_signer(&self, key_id: KeyId, new_signer: ManagedAddress) {
+        require!(!new_signer.is_zero(), "Invalid signer address");
+
+        let previous_signer = self.signers().insert(key_id, new_signer);
+        require!(previous_signer.is_some(), "Unknown signer key id");
+    }
+
+    /// A threshold of 0 disables M-of-N attestation for high-value actions
+    #[only_owner]
+    #[endpoint(setSignatureThreshold)]
+    fn set_signature_threshold(&self, threshold: usize) {
+        require!(
+            threshold <= self.signers().len(),
+            "Threshold higher than the number of signers"
+        );
+
+        self.signature_threshold().set(threshold);
+    }
+
+    /// Signed messages include the chain id, so signatures can't be replayed on another chain
+    #[only_owner]
+    #[endpoint(setChainId)]
+    fn set_chain_id(&self, chain_id: ManagedBuffer) {
+        self.set_chain_id_common(chain_id);
+    }
+
+    fn set_chain_id_common(&self, chain_id: ManagedBuffer) {
+        require!(!chain_id.is_empty(), "Invalid chain id");
+
+        self.chain_id().set(chain_id);
+    }
+
+    fn add_signer_common(&self, signer: ManagedAddress) -> KeyId {
+        require!(!signer.is_zero(), "Invalid signer address");
+
+        let key_id = self.last_signer_key_id().update(|last_key_id| {
+            *last_key_id += 1;
+            *last_key_id
+        });
+        let _ = self.signers().insert(key_id, signer);
+
+        key_id
+    }
+
+    fn get_signer_key(&self, key_id: KeyId) -> ManagedAddress {
+        let opt_signer = self.signers().get(&key_id);
+        require!(opt_signer.is_some(), "Unknown signer key id");
+
+        unsafe { opt_signer.unwrap_unchecked() }
+    }
+
+    /// Each signer signs the versioned message header, which includes their key id,
+    /// followed by the endpoint-specific message body
+    fn verify_signer_signature(
+        &self,
+        endpoint: SignedEndpoint,
+        message_body: &ManagedBuffer,
+        signer_signature: &SignerSignature<Self::Api>,
+    ) {
+        let signer = self.get_signer_key(signer_signature.key_id);
+        let chain_id_mapper = self.chain_id();
+        require!(!chain_id_mapper.is_empty(), "Chain id not set");
+
+        let mut signed_message = ManagedBuffer::new();
+        encode_message_header(
+            &mut signed_message,
+            &chain_id_mapper.get(),
+            &self.blockchain().get_sc_address(),
+            endpoint,
+            signer_signature.key_id,
+        );
+        signed_message.append(message_body);
+        if cfg!(debug_assertions) {
+            return;
+        }
+
+        self.crypto().verify_ed25519(
+            signer.as_managed_buffer(),
+            &signed_message,
+            signer_signature.signature.as_managed_buffer(),
+        );
+    }
+
+    /// Requires valid signatures from at least `threshold` distinct signers
+    /// Does nothing if threshold mode is disabled
+    fn check_threshold_attestation(
+        &self,
+        endpoint: SignedEndpoint,
+        message_body: &ManagedBuffer,
+        signatures: &ManagedVec<SignerSignature<Self::Api>>,
+    ) {
+        let threshold = self.signature_threshold().get();
+        if threshold == 0 {
+            return;
+        }
+
+        let mut signed_key_ids = ManagedVec::<Self::Api, KeyId>::new();
+        for signer_signature in signatures {
+            require!(
+                !signed_key_ids.contains(&signer_signature.key_id),
+                "Duplicate signer"
+            );
+
+            self.verify_signer_signature(endpoint, message_body, &signer_signature);
+            signed_key_ids.push(signer_signature.key_id);
+        }
+
+        require!(signed_key_ids.len() >= threshold, "Not enough signatures");
+    }
+
+    #[view(getSigners)]
+    fn get_signers(&self) -> MultiValueEncoded<MultiValue2<KeyId, ManagedAddress>> {
+        let mut signers = MultiValueEncoded::new();
+        for (key_id, signer) in self.signers().iter() {
+            signers.push((key_id, signer).into());
+        }
+
+        signers
+    }
+
+    #[storage_mapper("signers")]
+    fn signers(&self) -> MapMapper<KeyId, ManagedAddress>;
+
+    #[storage_mapper("lastSignerKeyId")]
+    fn last_signer_key_id(&self) -> SingleValueMapper<KeyId>;
+
+    /// Single signer set in init by previous versions, moved to the signers list on upgrade
+    #[storage_mapper("signer")]
+    fn legacy_signer(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getChainId)]
+    #[storage_mapper("chainId")]
+    fn chain_id(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getSignatureThreshold)]
+    #[storage_mapper("signatureThreshold")]
+    fn signature_threshold(&self) -> SingleValueMapper<usize>;
+}
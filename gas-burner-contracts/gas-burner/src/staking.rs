@@ -0,0 +1,174 @@
+use crate::week_timekeeping::Epoch;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type Multiplier = u64;
+
+/// 1x, in basis points
+pub const BASE_MULTIPLIER: Multiplier = 10_000;
+pub const MAX_MULTIPLIER: Multiplier = 10 * BASE_MULTIPLIER;
+pub const MAX_MULTIPLIER_TIERS: usize = 10;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct MultiplierTier<M: ManagedTypeApi> {
+    pub min_stake: BigUint<M>,
+    pub multiplier: Multiplier,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct UnbondingStake<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unbond_epoch: Epoch,
+}
+
+#[multiversx_sc::module]
+pub trait StakingModule:
+    crate::token::TokenModule
+    + crate::staking_pool::StakingPoolModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Tiers must be sorted by stake, with increasing multipliers
+    /// The highest tier reached by the user's stake applies to their work
+    #[only_owner]
+    #[endpoint(setMultiplierTiers)]
+    fn set_multiplier_tiers(&self, tiers: MultiValueEncoded<MultiValue2<BigUint, Multiplier>>) {
+        require!(tiers.len() <= MAX_MULTIPLIER_TIERS, "Too many tiers");
+
+        let mut multiplier_tiers = ManagedVec::new();
+        let mut prev_min_stake = BigUint::zero();
+        let mut prev_multiplier = BASE_MULTIPLIER;
+        for tier in tiers {
+            let (min_stake, multiplier) = tier.into_tuple();
+            require!(min_stake > prev_min_stake, "Tiers not sorted by stake");
+            require!(
+                multiplier > prev_multiplier && multiplier <= MAX_MULTIPLIER,
+                "Invalid multiplier"
+            );
+
+            prev_min_stake = min_stake.clone();
+            prev_multiplier = multiplier;
+            multiplier_tiers.push(MultiplierTier {
+                min_stake,
+                multiplier,
+            });
+        }
+
+        self.multiplier_tiers().set(multiplier_tiers);
+    }
+
+    #[only_owner]
+    #[endpoint(setUnbondEpochs)]
+    fn set_unbond_epochs(&self, unbond_epochs: Epoch) {
+        require!(
+            unbond_epochs > 0 || self.staking_pool_percentage().get() == 0,
+            "Unbond period required by the staking pool"
+        );
+
+        self.unbond_epochs().set(unbond_epochs);
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn stake(&self) {
+        self.require_not_paused();
+
+        let payment = self.call_value().single_esdt();
+        self.token().require_same_token(&payment.token_identifier);
+
+        let caller = self.blockchain().get_caller();
+        self.update_staking_rewards(&caller);
+        self.add_stake(&caller, &payment.amount);
+        self.total_staked()
+            .update(|total_staked| *total_staked += payment.amount);
+    }
+
+    /// The multiplier drops right away, the tokens can be withdrawn with unbond
+    /// once the unbond period passes
+    /// Unstaking again restarts the unbond period for the whole unbonding amount
+    #[endpoint]
+    fn unstake(&self, amount: BigUint) {
+        require!(amount > 0, "Invalid amount");
+
+        let caller = self.blockchain().get_caller();
+        self.update_staking_rewards(&caller);
+        self.remove_stake(&caller, &amount);
+
+        let unbond_epoch = self.blockchain().get_block_epoch() + self.unbond_epochs().get();
+        let unbonding_mapper = self.unbonding_stake(&caller);
+        let mut unbonding_amount = amount;
+        if !unbonding_mapper.is_empty() {
+            unbonding_amount += unbonding_mapper.get().amount;
+        }
+        unbonding_mapper.set(UnbondingStake {
+            amount: unbonding_amount,
+            unbond_epoch,
+        });
+    }
+
+    #[endpoint]
+    fn unbond(&self) -> EsdtTokenPayment {
+        let caller = self.blockchain().get_caller();
+        let unbonding_mapper = self.unbonding_stake(&caller);
+        require!(!unbonding_mapper.is_empty(), "Nothing to unbond");
+
+        let unbonding_stake = unbonding_mapper.take();
+        require!(
+            self.blockchain().get_block_epoch() >= unbonding_stake.unbond_epoch,
+            "Unbond period not over"
+        );
+
+        self.total_staked()
+            .update(|total_staked| *total_staked -= &unbonding_stake.amount);
+
+        let payment = EsdtTokenPayment::new(self.token().get_token_id(), 0, unbonding_stake.amount);
+        self.send()
+            .direct_esdt(&caller, &payment.token_identifier, 0, &payment.amount);
+
+        payment
+    }
+
+    /// Only stake held since the start of the week counts,
+    /// so staking right before working and unstaking after earns nothing
+    #[view(getWorkMultiplier)]
+    fn get_work_multiplier(&self, user: ManagedAddress) -> Multiplier {
+        let current_week = self.get_current_week();
+        let mut staked_amount = self.staked_amount(&user).get();
+        for pending_stake in self.user_pending_stake(&user).get().iter() {
+            if pending_stake.week == current_week {
+                staked_amount -= pending_stake.amount;
+            }
+        }
+
+        let mut multiplier = BASE_MULTIPLIER;
+        for tier in self.multiplier_tiers().get().iter() {
+            if staked_amount < tier.min_stake {
+                break;
+            }
+
+            multiplier = tier.multiplier;
+        }
+
+        multiplier
+    }
+
+    #[view(getMultiplierTiers)]
+    #[storage_mapper("multiplierTiers")]
+    fn multiplier_tiers(&self) -> SingleValueMapper<ManagedVec<MultiplierTier<Self::Api>>>;
+
+    #[view(getUnbondingStake)]
+    #[storage_mapper("unbondingStake")]
+    fn unbonding_stake(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<UnbondingStake<Self::Api>>;
+
+    /// Staked and unbonding tokens, owed to the users
+    #[view(getTotalStaked)]
+    #[storage_mapper("totalStaked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+}
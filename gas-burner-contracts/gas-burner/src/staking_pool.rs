@@ -0,0 +1,296 @@
+use crate::{
+    pause_flags::PauseFlag,
+    week_timekeeping::{Epoch, Week},
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_PERCENTAGE: u32 = 10_000;
+pub const REWARD_PER_SHARE_PRECISION: u64 = 1_000_000_000_000_000_000;
+
+/// Tokens staked during the given week
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct PendingStake<M: ManagedTypeApi> {
+    pub week: Week,
+    pub amount: BigUint<M>,
+}
+
+/// Stakers earn a share of each week's dev rewards, proportional to their active stake
+/// Stake only becomes active once it was held for a full week, and earns from that week's rewards,
+/// so staking right before the rewards are distributed earns nothing
+/// Unbonding tokens no longer earn rewards
+#[multiversx_sc::module]
+pub trait StakingPoolModule:
+    crate::week_timekeeping::WeekTimekeepingModule
+    + crate::pause_flags::PauseFlagsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Percentage of the weekly dev rewards, out of MAX_PERCENTAGE, that goes to stakers
+    /// The pool can only be enabled with a non-zero unbond period
+    #[only_owner]
+    #[endpoint(setStakingPoolPercentage)]
+    fn set_staking_pool_percentage(&self, percentage: u32) {
+        require!(percentage <= MAX_PERCENTAGE, "Invalid percentage");
+        require!(
+            percentage == 0 || self.unbond_epochs().get() > 0,
+            "Unbond period required by the staking pool"
+        );
+
+        self.staking_pool_percentage().set(percentage);
+    }
+
+    #[endpoint(claimStakingRewards)]
+    fn claim_staking_rewards(&self) -> BigUint {
+        self.require_flag_not_paused(PauseFlag::Claims);
+
+        let caller = self.blockchain().get_caller();
+        self.update_staking_rewards(&caller);
+
+        let rewards = self.unclaimed_staking_rewards(&caller).take();
+        self.staking_rewards_outstanding()
+            .update(|outstanding| *outstanding -= &rewards);
+        self.send().direct_non_zero_egld(&caller, &rewards);
+
+        rewards
+    }
+
+    #[view(getClaimableStakingRewards)]
+    fn get_claimable_staking_rewards(&self, user: ManagedAddress) -> BigUint {
+        self.unclaimed_staking_rewards(&user).get() + self.get_pending_staking_rewards(&user)
+    }
+
+    /// Returns the share taken by the pool, nothing is taken while no stake is active
+    fn distribute_staking_pool_share(&self, rewards: &BigUint) -> BigUint {
+        self.activate_pending_stake();
+
+        let total_active_stake = self.total_active_stake().get();
+        let percentage = self.staking_pool_percentage().get();
+        if total_active_stake == 0 || percentage == 0 {
+            return BigUint::zero();
+        }
+
+        let pool_share = rewards * percentage / MAX_PERCENTAGE;
+        self.reward_per_share().update(|reward_per_share| {
+            *reward_per_share += &pool_share * REWARD_PER_SHARE_PRECISION / &total_active_stake
+        });
+        self.staking_rewards_outstanding()
+            .update(|outstanding| *outstanding += &pool_share);
+
+        pool_share
+    }
+
+    /// Must be called before every change of the user's stake
+    fn update_staking_rewards(&self, user: &ManagedAddress) {
+        self.activate_pending_stake();
+
+        let pending_rewards = self.get_pending_staking_rewards(user);
+        if pending_rewards > 0 {
+            self.unclaimed_staking_rewards(user)
+                .update(|unclaimed| *unclaimed += pending_rewards);
+        }
+
+        let current_week = self.get_current_week();
+        let user_pending_stake_mapper = self.user_pending_stake(user);
+        let mut user_pending_stake = ManagedVec::new();
+        for pending_stake in user_pending_stake_mapper.get().iter() {
+            if !self.is_stake_active(pending_stake.week, current_week) {
+                user_pending_stake.push(pending_stake);
+            }
+        }
+        user_pending_stake_mapper.set(user_pending_stake);
+
+        self.user_reward_per_share(user)
+            .set(self.reward_per_share().get());
+    }
+
+    /// New stake earns from the rewards of the next week on
+    fn add_stake(&self, user: &ManagedAddress, amount: &BigUint) {
+        let current_week = self.get_current_week();
+        self.user_pending_stake(user).update(|user_pending_stake| {
+            add_pending_stake(user_pending_stake, current_week, amount)
+        });
+        self.pending_stake()
+            .update(|pending_stake| add_pending_stake(pending_stake, current_week, amount));
+        self.staked_amount(user)
+            .update(|staked_amount| *staked_amount += amount);
+    }
+
+    /// The stake that is not active yet is removed first, starting with the latest
+    fn remove_stake(&self, user: &ManagedAddress, amount: &BigUint) {
+        let staked_amount_mapper = self.staked_amount(user);
+        let staked_amount = staked_amount_mapper.get();
+        require!(staked_amount >= *amount, "Not enough staked");
+        staked_amount_mapper.set(staked_amount - amount);
+
+        let mut remaining_amount = amount.clone();
+        let mut user_pending_stake = self.user_pending_stake(user).get();
+        let mut pending_stake = self.pending_stake().get();
+        while remaining_amount > 0 && !user_pending_stake.is_empty() {
+            let last_index = user_pending_stake.len() - 1;
+            let last_pending_stake = user_pending_stake.get(last_index);
+            let removed_amount =
+                core::cmp::min(&last_pending_stake.amount, &remaining_amount).clone();
+            remove_pending_stake(&mut pending_stake, last_pending_stake.week, &removed_amount);
+            remove_pending_stake(
+                &mut user_pending_stake,
+                last_pending_stake.week,
+                &removed_amount,
+            );
+            remaining_amount -= removed_amount;
+        }
+        self.user_pending_stake(user).set(user_pending_stake);
+        self.pending_stake().set(pending_stake);
+
+        self.total_active_stake()
+            .update(|total_active_stake| *total_active_stake -= remaining_amount);
+    }
+
+    /// Stake of the weeks before the previous one becomes active,
+    /// the previous week being the one whose rewards are distributed next
+    fn activate_pending_stake(&self) {
+        let current_week = self.get_current_week();
+        let pending_stake_mapper = self.pending_stake();
+        let reward_per_share = self.reward_per_share().get();
+        let mut still_pending_stake = ManagedVec::new();
+        let mut activated_amount = BigUint::zero();
+        for pending_stake in pending_stake_mapper.get().iter() {
+            if self.is_stake_active(pending_stake.week, current_week) {
+                self.reward_per_share_on_activation(pending_stake.week)
+                    .set(&reward_per_share);
+                activated_amount += pending_stake.amount;
+            } else {
+                still_pending_stake.push(pending_stake);
+            }
+        }
+
+        if activated_amount > 0 {
+            pending_stake_mapper.set(still_pending_stake);
+            self.total_active_stake()
+                .update(|total_active_stake| *total_active_stake += activated_amount);
+        }
+    }
+
+    fn is_stake_active(&self, stake_week: Week, current_week: Week) -> bool {
+        stake_week + 1 < current_week
+    }
+
+    /// Active stake earns since the user's last update,
+    /// stake activated since then only earns since its activation
+    fn get_pending_staking_rewards(&self, user: &ManagedAddress) -> BigUint {
+        let current_week = self.get_current_week();
+        let reward_per_share = self.reward_per_share().get();
+        let pending_stake = self.pending_stake().get();
+        let mut user_active_stake = self.staked_amount(user).get();
+        let mut rewards = BigUint::zero();
+        for user_pending_stake in self.user_pending_stake(user).get().iter() {
+            user_active_stake -= &user_pending_stake.amount;
+            let is_activated = self.is_stake_active(user_pending_stake.week, current_week)
+                && !pending_stake
+                    .iter()
+                    .any(|stake| stake.week == user_pending_stake.week);
+            if is_activated {
+                let reward_per_share_diff = &reward_per_share
+                    - &self
+                        .reward_per_share_on_activation(user_pending_stake.week)
+                        .get();
+                rewards += user_pending_stake.amount * reward_per_share_diff;
+            }
+        }
+
+        let reward_per_share_diff = reward_per_share - self.user_reward_per_share(user).get();
+        rewards += user_active_stake * reward_per_share_diff;
+
+        rewards / REWARD_PER_SHARE_PRECISION
+    }
+
+    #[view(getStakingPoolPercentage)]
+    #[storage_mapper("stakingPoolPercentage")]
+    fn staking_pool_percentage(&self) -> SingleValueMapper<u32>;
+
+    #[view(getStakedAmount)]
+    #[storage_mapper("stakedAmount")]
+    fn staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Staked tokens earning rewards, without the pending and unbonding ones
+    #[view(getTotalActiveStake)]
+    #[storage_mapper("totalActiveStake")]
+    fn total_active_stake(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stake of the current and previous weeks, not active yet
+    #[view(getPendingStake)]
+    #[storage_mapper("pendingStake")]
+    fn pending_stake(&self) -> SingleValueMapper<ManagedVec<PendingStake<Self::Api>>>;
+
+    #[view(getUserPendingStake)]
+    #[storage_mapper("userPendingStake")]
+    fn user_pending_stake(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<PendingStake<Self::Api>>>;
+
+    #[storage_mapper("rewardPerShareOnActivation")]
+    fn reward_per_share_on_activation(&self, week: Week) -> SingleValueMapper<BigUint>;
+
+    /// Must be non-zero while the staking pool is enabled
+    #[view(getUnbondEpochs)]
+    #[storage_mapper("unbondEpochs")]
+    fn unbond_epochs(&self) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("rewardPerShare")]
+    fn reward_per_share(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userRewardPerShare")]
+    fn user_reward_per_share(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("unclaimedStakingRewards")]
+    fn unclaimed_staking_rewards(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// EGLD owed to stakers, kept apart from the leaderboard rewards
+    #[view(getStakingRewardsOutstanding)]
+    #[storage_mapper("stakingRewardsOutstanding")]
+    fn staking_rewards_outstanding(&self) -> SingleValueMapper<BigUint>;
+}
+
+fn add_pending_stake<M: ManagedTypeApi>(
+    pending_stake: &mut ManagedVec<M, PendingStake<M>>,
+    week: Week,
+    amount: &BigUint<M>,
+) {
+    let mut updated_pending_stake = ManagedVec::new();
+    let mut is_added = false;
+    for mut stake in pending_stake.iter() {
+        if stake.week == week {
+            stake.amount += amount;
+            is_added = true;
+        }
+        updated_pending_stake.push(stake);
+    }
+    if !is_added {
+        updated_pending_stake.push(PendingStake {
+            week,
+            amount: amount.clone(),
+        });
+    }
+
+    *pending_stake = updated_pending_stake;
+}
+
+fn remove_pending_stake<M: ManagedTypeApi>(
+    pending_stake: &mut ManagedVec<M, PendingStake<M>>,
+    week: Week,
+    amount: &BigUint<M>,
+) {
+    let mut updated_pending_stake = ManagedVec::new();
+    for mut stake in pending_stake.iter() {
+        if stake.week == week {
+            stake.amount -= amount;
+        }
+        if stake.amount > 0 {
+            updated_pending_stake.push(stake);
+        }
+    }
+
+    *pending_stake = updated_pending_stake;
+}
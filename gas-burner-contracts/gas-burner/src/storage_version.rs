@@ -0,0 +1,24 @@
+multiversx_sc::imports!();
+
+pub type StorageVersion = u32;
+
+/// Version of deployments from before storage versioning was introduced
+pub const LEGACY_STORAGE_VERSION: StorageVersion = 1;
+/// Claimed flags kept per user
+pub const PER_USER_CLAIMS_STORAGE_VERSION: StorageVersion = 2;
+/// Leaderboard entries hold both raw gas and score
+pub const CURRENT_STORAGE_VERSION: StorageVersion = 3;
+
+#[multiversx_sc::module]
+pub trait StorageVersionModule {
+    fn require_storage_migrated(&self) {
+        require!(
+            self.storage_version().get() == CURRENT_STORAGE_VERSION,
+            "Storage migration in progress"
+        );
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<StorageVersion>;
+}
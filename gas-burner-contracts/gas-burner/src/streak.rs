@@ -0,0 +1,133 @@
+use crate::{
+    staking::{Multiplier, BASE_MULTIPLIER, MAX_MULTIPLIER},
+    week_timekeeping::Week,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// A week counts towards the streak once the user's raw gas for it reaches min_weekly_gas
+/// Each week of the streak before the current one adds bonus_per_week,
+/// up to max_bonus, to the multiplier of the current week's work
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct StreakConfig<M: ManagedTypeApi> {
+    pub min_weekly_gas: BigUint<M>,
+    pub bonus_per_week: Multiplier,
+    pub max_bonus: Multiplier,
+}
+
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub struct UserStreak {
+    pub length: usize,
+    pub last_week: Week,
+}
+
+#[multiversx_sc::module]
+pub trait StreakModule:
+    crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::week_timekeeping::WeekTimekeepingModule
+{
+    /// Streaks are tracked even while no config is set, with any work counting
+    #[only_owner]
+    #[endpoint(setStreakConfig)]
+    fn set_streak_config(&self, config: StreakConfig<Self::Api>) {
+        require!(
+            config.bonus_per_week <= config.max_bonus && config.max_bonus <= MAX_MULTIPLIER,
+            "Invalid streak bonus"
+        );
+
+        self.streak_config().set(config);
+    }
+
+    /// Consecutive qualifying weeks, up to the current one
+    /// A streak is still ongoing if the user has not qualified yet for the current week
+    #[view(getStreak)]
+    fn get_streak(&self, user: ManagedAddress) -> usize {
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 {
+            return 0;
+        }
+
+        let user_streak_mapper = self.user_streak(user_id);
+        if user_streak_mapper.is_empty() {
+            return 0;
+        }
+
+        let user_streak = user_streak_mapper.get();
+        if user_streak.last_week + 1 < self.get_current_week() {
+            return 0;
+        }
+
+        user_streak.length
+    }
+
+    /// Multiplier applied to the user's work for the current week
+    #[view(getStreakMultiplier)]
+    fn get_streak_multiplier(&self, user: ManagedAddress) -> Multiplier {
+        let user_id = self.user_id().get_id(&user);
+        if user_id == 0 {
+            return BASE_MULTIPLIER;
+        }
+
+        self.get_streak_multiplier_for_week(user_id, self.get_current_week())
+    }
+
+    fn get_streak_multiplier_for_week(&self, user_id: AddressId, week: Week) -> Multiplier {
+        let config_mapper = self.streak_config();
+        let user_streak_mapper = self.user_streak(user_id);
+        if config_mapper.is_empty() || user_streak_mapper.is_empty() {
+            return BASE_MULTIPLIER;
+        }
+
+        let user_streak = user_streak_mapper.get();
+        let previous_weeks = if user_streak.last_week == week {
+            user_streak.length - 1
+        } else if user_streak.last_week + 1 == week {
+            user_streak.length
+        } else {
+            0
+        };
+
+        let config = config_mapper.get();
+        let bonus = core::cmp::min(
+            previous_weeks as Multiplier * config.bonus_per_week,
+            config.max_bonus,
+        );
+
+        BASE_MULTIPLIER + bonus
+    }
+
+    /// Called with the user's raw gas for the week after each work
+    fn update_streak(&self, user_id: AddressId, week: Week, raw_gas: &BigUint) {
+        let config_mapper = self.streak_config();
+        if !config_mapper.is_empty() && *raw_gas < config_mapper.get().min_weekly_gas {
+            return;
+        }
+
+        let user_streak_mapper = self.user_streak(user_id);
+        let mut user_streak = UserStreak {
+            length: 1,
+            last_week: week,
+        };
+        if !user_streak_mapper.is_empty() {
+            let prev_user_streak = user_streak_mapper.get();
+            if prev_user_streak.last_week == week {
+                return;
+            }
+            if prev_user_streak.last_week + 1 == week {
+                user_streak.length = prev_user_streak.length + 1;
+            }
+        }
+
+        user_streak_mapper.set(user_streak);
+    }
+
+    #[view(getStreakConfig)]
+    #[storage_mapper("streakConfig")]
+    fn streak_config(&self) -> SingleValueMapper<StreakConfig<Self::Api>>;
+
+    #[storage_mapper("userStreak")]
+    fn user_streak(&self, user_id: AddressId) -> SingleValueMapper<UserStreak>;
+}
@@ -0,0 +1,124 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait TokenModule {
+    /// Issues the GAS token with every local role, including Mint, for this SC
+    /// The issue cost is paid in EGLD and refunded to the owner if the issue fails
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueToken)]
+    fn issue_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        require!(issue_cost > 0, "No EGLD sent");
+
+        self.token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            Some(self.callbacks().issue_token_callback()),
+        );
+    }
+
+    #[callback]
+    fn issue_token_callback(&self, #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.token().set_token_id(token_id.clone());
+                self.token_issued_event(&token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.token().clear();
+                self.refund_issue_cost();
+            }
+        }
+    }
+
+    /// Issues the meta-ESDT used for locked rewards, with every local role for this SC
+    /// The issue cost is paid in EGLD and refunded to the owner if the issue fails
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueLockedToken)]
+    fn issue_locked_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        require!(issue_cost > 0, "No EGLD sent");
+
+        self.locked_token().issue_and_set_all_roles(
+            EsdtTokenType::Meta,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            Some(self.callbacks().issue_locked_token_callback()),
+        );
+    }
+
+    #[callback]
+    fn issue_locked_token_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.locked_token().set_token_id(token_id.clone());
+                self.token_issued_event(&token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.locked_token().clear();
+                self.refund_issue_cost();
+            }
+        }
+    }
+
+    fn refund_issue_cost(&self) {
+        let refund = self.call_value().egld_value().clone_value();
+        let owner = self.blockchain().get_owner_address();
+        self.send().direct_non_zero_egld(&owner, &refund);
+    }
+
+    /// The roles are set after the issue, so the Mint role is checked on every mint
+    fn require_mint_role(&self) {
+        require!(self.token().get_token_state().is_set(), "Token not issued");
+
+        let roles = self
+            .blockchain()
+            .get_esdt_local_roles(self.token().get_token_id_ref());
+        require!(roles.has_role(&EsdtLocalRole::Mint), "Mint role not set");
+    }
+
+    fn require_locked_token_roles(&self) {
+        require!(
+            self.locked_token().get_token_state().is_set(),
+            "Locked token not issued"
+        );
+
+        let roles = self
+            .blockchain()
+            .get_esdt_local_roles(self.locked_token().get_token_id_ref());
+        require!(
+            roles.has_role(&EsdtLocalRole::NftCreate) && roles.has_role(&EsdtLocalRole::NftBurn),
+            "Locked token roles not set"
+        );
+    }
+
+    #[view(getTokenId)]
+    #[storage_mapper("tokenId")]
+    fn token(&self) -> FungibleTokenMapper;
+
+    #[view(getLockedTokenId)]
+    #[storage_mapper("lockedTokenId")]
+    fn locked_token(&self) -> NonFungibleTokenMapper;
+
+    #[event("tokenIssued")]
+    fn token_issued_event(&self, #[indexed] token_id: &TokenIdentifier);
+}
@@ -1,4 +1,7 @@
-use crate::week_timekeeping::Week;
+use crate::{
+    pause_flags::PauseFlag, proof_of_work::WorkVerificationMode, signature::Nonce,
+    signed_message::SignedEndpoint, week_timekeeping::Week,
+};
 
 multiversx_sc::imports!();
 
@@ -8,30 +11,123 @@ const GAS_TO_FINISH_ENDPOINT: u64 = 1_000;
 #[multiversx_sc::module]
 pub trait WorkModule:
     crate::signature::SignatureModule
+    + crate::signers::SignersModule
+    + crate::proof_of_work::ProofOfWorkModule
+    + crate::session_key::SessionKeyModule
     + crate::leaderboard::LeaderboardModule
+    + crate::league::LeagueModule
+    + crate::guild::GuildModule
+    + crate::boost::BoostModule
+    + crate::rewards::RewardsModule
+    + crate::referral::ReferralModule
+    + crate::storage_version::StorageVersionModule
+    + crate::staking::StakingModule
+    + crate::scoring::ScoringModule
+    + crate::streak::StreakModule
+    + crate::staking_pool::StakingPoolModule
     + crate::week_timekeeping::WeekTimekeepingModule
+    + crate::pause_flags::PauseFlagsModule
+    + crate::token::TokenModule
+    + crate::locked_token::LockedTokenModule
     + multiversx_sc_modules::pause::PauseModule
 {
-    /// Signature uses ed25519 and you must sign the message of user_address + nonce
+    /// In signature mode, the proof is an authorized signer's WorkSignature for the user and nonce
+    /// In proof of work mode, the proof is a solution for the current week's puzzle
     /// Nonce starts from 0 and you can get it through the getUserNonce view
+    /// Any unused nonce within the window may be given, so several transactions can be in flight
     #[endpoint]
-    fn work(&self, signature: ManagedBuffer) -> EsdtTokenPayment {
-        self.require_not_paused();
+    fn work(&self, proof: ManagedBuffer, opt_nonce: OptionalValue<Nonce>) -> EsdtTokenPayment {
+        self.require_flag_not_paused(PauseFlag::Work);
 
         let gas_left = self.blockchain().get_gas_left();
+        let caller = self.blockchain().get_caller();
+        self.work_common(&caller, SignedEndpoint::Work, &proof, opt_nonce, gas_left)
+    }
+
+    /// Same as work, for a user's first work only, crediting the referrer with kickbacks
+    /// on the user's work, see the referral module
+    #[endpoint(workWithReferrer)]
+    fn work_with_referrer(
+        &self,
+        referrer: ManagedAddress,
+        proof: ManagedBuffer,
+        opt_nonce: OptionalValue<Nonce>,
+    ) -> EsdtTokenPayment {
+        self.require_flag_not_paused(PauseFlag::Work);
+
+        let gas_left = self.blockchain().get_gas_left();
+        let caller = self.blockchain().get_caller();
+        self.register_referrer(&caller, &referrer);
+
+        self.work_common(&caller, SignedEndpoint::Work, &proof, opt_nonce, gas_left)
+    }
+
+    /// Called by a user's delegate, registered through registerSessionKey
+    /// Rewards and leaderboard progress go to the user
+    /// The gas used counts against the session key's allowance
+    /// The proof is built for the user, the same as for work
+    #[endpoint(workFor)]
+    fn work_for(
+        &self,
+        user: ManagedAddress,
+        proof: ManagedBuffer,
+        opt_nonce: OptionalValue<Nonce>,
+    ) -> EsdtTokenPayment {
+        self.require_flag_not_paused(PauseFlag::Work);
+
+        let gas_left = self.blockchain().get_gas_left();
+        let caller = self.blockchain().get_caller();
+        self.use_session_key(&user, &caller, gas_left);
+
+        self.work_common(&user, SignedEndpoint::WorkFor, &proof, opt_nonce, gas_left)
+    }
+
+    fn work_common(
+        &self,
+        user: &ManagedAddress,
+        endpoint: SignedEndpoint,
+        proof: &ManagedBuffer,
+        opt_nonce: OptionalValue<Nonce>,
+        gas_left: u64,
+    ) -> EsdtTokenPayment {
+        self.require_storage_migrated();
+
         let tokens_to_send = gas_left / DIV_CONST;
         require!(tokens_to_send > 0, "Gas too low");
 
-        let caller = self.blockchain().get_caller();
-        let minted_tokens = self.token().mint_and_send(&caller, tokens_to_send.into());
+        let minted_tokens = self.mint_rewards(user, tokens_to_send.into());
 
-        self.check_worker_signature(&caller, &signature);
-        self.increase_leaderboard_entry(&caller, gas_left);
+        self.check_work_proof(user, endpoint, proof, opt_nonce);
+        let boost_multiplier = self.get_boost_multiplier(user.clone());
+        let score_increase = self.increase_leaderboard_entry(user, gas_left, boost_multiplier);
+        let user_id = self.user_id().get_id(user);
+        self.increase_team_entries(user_id, gas_left, &score_increase);
+        self.pay_referral_kickback(user, &minted_tokens.amount, &score_increase);
         self.use_remaining_gas();
 
         minted_tokens
     }
 
+    fn check_work_proof(
+        &self,
+        user: &ManagedAddress,
+        endpoint: SignedEndpoint,
+        proof: &ManagedBuffer,
+        opt_nonce: OptionalValue<Nonce>,
+    ) {
+        let user_nonce = self.use_user_nonce(user, opt_nonce);
+        match self.work_verification_mode().get() {
+            WorkVerificationMode::Signature => {
+                self.check_worker_signature(user, endpoint, user_nonce, proof)
+            }
+            WorkVerificationMode::ProofOfWork => self.check_proof_of_work(user, user_nonce, proof),
+        }
+
+        let current_week = self.get_current_week();
+        self.submissions_for_week(current_week)
+            .update(|submissions| *submissions += 1);
+    }
+
     fn use_remaining_gas(&self) {
         if cfg!(debug_assertions) {
             return;
@@ -43,10 +139,6 @@ pub trait WorkModule:
         }
     }
 
-    #[view(getTokenId)]
-    #[storage_mapper("tokenId")]
-    fn token(&self) -> FungibleTokenMapper;
-
     #[storage_mapper("workersForWeek")]
     fn workers_for_week(&self, week: Week) -> UnorderedSetMapper<AddressId>;
 
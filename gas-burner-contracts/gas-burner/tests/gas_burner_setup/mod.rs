@@ -1,12 +1,24 @@
-use gas_burner::{work::WorkModule, GasBurner};
-use multiversx_sc::types::{Address, EsdtLocalRole};
+use gas_burner::{
+    proof_of_work::ProofOfWorkModule, signature::SignatureModule,
+    week_timekeeping::WeekTimekeepingModule, work::WorkModule, GasBurner,
+};
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, EsdtLocalRole},
+};
 use multiversx_sc_scenario::{
     imports::{BlockchainStateWrapper, ContractObjWrapper},
-    managed_address, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+    managed_address, managed_buffer, managed_token_id, rust_biguint,
+    whitebox_legacy::TxResult,
+    DebugApi,
+};
+use owner_sc::{
+    governance::{GovernanceModule, ProposalAction, DEFAULT_TIMELOCK},
+    OwnerSc,
 };
-use owner_sc::OwnerSc;
 
 pub static GAS_BURNER_TOKEN_ID: &[u8] = b"GAS-123456";
+pub static CHAIN_ID: &[u8] = b"D";
 
 pub struct GasBurnerSetup<GasBurnerBuilder, OwnerScBuilder>
 where
@@ -43,12 +55,22 @@ where
             "gas burner",
         );
 
+        // the timelock is cleared, so later proposals can be executed right away
         b_mock
             .execute_tx(&owner, &owner_sc_wrapper, &rust_zero, |sc| {
                 sc.init();
 
-                sc.gas_burner()
-                    .set(managed_address!(gas_burner_wrapper.address_ref()));
+                sc.propose(ProposalAction::AddGasBurner(managed_address!(
+                    gas_burner_wrapper.address_ref()
+                )));
+                sc.propose(ProposalAction::SetTimelock(0));
+            })
+            .assert_ok();
+        b_mock.set_block_timestamp(DEFAULT_TIMELOCK);
+        b_mock
+            .execute_tx(&owner, &owner_sc_wrapper, &rust_zero, |sc| {
+                sc.execute_proposal(1);
+                sc.execute_proposal(2);
             })
             .assert_ok();
 
@@ -57,14 +79,16 @@ where
                 sc.init(
                     managed_address!(owner_sc_wrapper.address_ref()),
                     managed_address!(&signer),
-                    managed_token_id!(GAS_BURNER_TOKEN_ID),
+                    managed_buffer!(CHAIN_ID),
+                    OptionalValue::Some(managed_token_id!(GAS_BURNER_TOKEN_ID)),
                 );
             })
             .assert_ok();
 
         b_mock
             .execute_tx(&owner, &owner_sc_wrapper, &rust_zero, |sc| {
-                sc.unpause_gas_burner();
+                let proposal_id = sc.propose(ProposalAction::UnpauseAllGasBurners);
+                sc.execute_proposal(proposal_id);
             })
             .assert_ok();
 
@@ -88,8 +112,50 @@ where
     pub fn work_user(&mut self, user: &Address) {
         self.b_mock
             .execute_tx(user, &self.gas_burner_wrapper, &rust_biguint!(0), |sc| {
-                sc.work(managed_buffer!(b"signature"));
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
             })
             .assert_ok();
     }
+
+    /// Proposes and executes the action right away, with the default quorum and no timelock
+    pub fn execute_owner_proposal<ActionBuilder>(
+        &mut self,
+        action_builder: ActionBuilder,
+    ) -> TxResult
+    where
+        ActionBuilder: Fn() -> ProposalAction<DebugApi>,
+    {
+        self.b_mock.execute_tx(
+            &self.owner,
+            &self.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let proposal_id = sc.propose(action_builder());
+                sc.execute_proposal(proposal_id);
+            },
+        )
+    }
+
+    pub fn find_pow_solution(&mut self, user: &Address) -> Vec<u8> {
+        let mut pow_solution = Vec::new();
+        self.b_mock
+            .execute_query(&self.gas_burner_wrapper, |sc| {
+                let user_address = managed_address!(user);
+                let user_nonce = sc.get_user_nonce(user_address.clone());
+                let current_week = sc.get_current_week();
+                let difficulty = sc.get_pow_difficulty(current_week);
+                for candidate in 0u64.. {
+                    let solution = managed_buffer!(&candidate.to_be_bytes());
+                    let pow_hash =
+                        sc.compute_pow_hash(&user_address, user_nonce, current_week, &solution);
+                    if sc.has_leading_zero_bits(&pow_hash, difficulty) {
+                        pow_solution = candidate.to_be_bytes().to_vec();
+                        break;
+                    }
+                }
+            })
+            .assert_ok();
+
+        pow_solution
+    }
 }
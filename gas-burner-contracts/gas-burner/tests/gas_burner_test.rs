@@ -1,13 +1,70 @@
 use gas_burner::{
+    boost::{BoostConfig, BoostModule},
+    guild::{GuildConfig, GuildModule},
     leaderboard::{LeaderboardEntry, LeaderboardModule},
+    league::{Division, LeagueConfig, LeagueModule},
+    locked_token::{LockedTokenAttributes, LockedTokenModule},
+    migration::{LegacyLeaderboardEntry, MigrationModule},
+    pause_flags::{PauseFlagsModule, PauseStatus},
+    proof_of_work::ProofOfWorkModule,
+    pruning::{PruningModule, WeekSummary},
+    recovery::RecoveryModule,
+    referral::{ReferralConfig, ReferralModule, ReferralRewardKind},
     rewards::RewardsModule,
+    scoring::{ScoringCurve, ScoringModule, ScoringSegment},
+    session_key::SessionKeyModule,
+    signature::SignatureModule,
+    signed_message::{
+        encode_message_header, encode_work_message_body, offchain::build_work_message,
+        SignedEndpoint, SIGNED_MESSAGE_PREFIX,
+    },
+    signers::{SignerSignature, SignersModule},
+    staking::{StakingModule, BASE_MULTIPLIER, MAX_MULTIPLIER},
+    staking_pool::StakingPoolModule,
+    storage_version::StorageVersionModule,
+    streak::{StreakConfig, StreakModule},
+    token::TokenModule,
     work::WorkModule,
+    GasBurner,
+};
+use gas_burner_setup::{GasBurnerSetup, CHAIN_ID, GAS_BURNER_TOKEN_ID};
+use multiversx_sc::{
+    codec::{
+        multi_types::{MultiValue2, OptionalValue},
+        TopEncode,
+    },
+    contract_base::ContractBase,
+    storage::mappers::StorageTokenWrapper,
+    types::{
+        CodeMetadata, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress,
+        ManagedAsyncCallError, ManagedAsyncCallResult, ManagedBuffer, ManagedByteArray, ManagedVec,
+        MultiValueEncoded,
+    },
+};
+use multiversx_sc_modules::pause::PauseModule;
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use owner_sc::{
+    dev_rewards_split::{DevRewardsClaim, DevRewardsSplit, DevRewardsSplitModule},
+    gas_burner::{
+        GasBurnerModule, GasBurnerTokenIssue, GasBurnerTokenKind, GasBurnerUpgrade, UpgradeSource,
+    },
+    gas_burner_proxy::{
+        LockSchedule, MintMode, PauseFlag as OwnerScPauseFlag, WorkVerificationMode,
+    },
+    governance::{GovernanceModule, ProposalAction, DEFAULT_TIMELOCK, PROPOSAL_LIFETIME},
+    migration::MigrationModule as OwnerScMigrationModule,
+    reserve::ReserveModule,
+    roles::{AdminRole, RolesModule},
+    OwnerSc,
 };
-use gas_burner_setup::{GasBurnerSetup, GAS_BURNER_TOKEN_ID};
-use multiversx_sc_scenario::{managed_biguint, managed_buffer, rust_biguint};
 
 pub mod gas_burner_setup;
 
+static NEW_GAS_BURNER_TOKEN_ID: &[u8] = b"GAS-654321";
+static LOCKED_TOKEN_ID: &[u8] = b"LGAS-123456";
+
 #[test]
 fn init_test() {
     let _ = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
@@ -24,14 +81,15 @@ fn work_test() {
             &setup.gas_burner_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.work(managed_buffer!(b"signature"));
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
 
                 assert_eq!(sc.leaderboard(1).len(), 1);
                 assert_eq!(
                     sc.leaderboard(1).get(1),
                     LeaderboardEntry {
                         user_id: 1,
-                        work_amount: managed_biguint!(100_000_000)
+                        raw_gas: managed_biguint!(100_000_000),
+                        score: managed_biguint!(100_000_000)
                     }
                 );
             },
@@ -62,14 +120,16 @@ fn leaderboard_test() {
                 sc.leaderboard(1).get(1),
                 LeaderboardEntry {
                     user_id: 1,
-                    work_amount: managed_biguint!(100_000_000)
+                    raw_gas: managed_biguint!(100_000_000),
+                    score: managed_biguint!(100_000_000)
                 }
             );
             assert_eq!(
                 sc.leaderboard(1).get(2),
                 LeaderboardEntry {
                     user_id: 2,
-                    work_amount: managed_biguint!(100_000_000)
+                    raw_gas: managed_biguint!(100_000_000),
+                    score: managed_biguint!(100_000_000)
                 }
             );
         })
@@ -85,14 +145,16 @@ fn leaderboard_test() {
                 sc.leaderboard(1).get(1),
                 LeaderboardEntry {
                     user_id: 2,
-                    work_amount: managed_biguint!(200_000_000)
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(200_000_000)
                 }
             );
             assert_eq!(
                 sc.leaderboard(1).get(2),
                 LeaderboardEntry {
                     user_id: 1,
-                    work_amount: managed_biguint!(100_000_000)
+                    raw_gas: managed_biguint!(100_000_000),
+                    score: managed_biguint!(100_000_000)
                 }
             );
         })
@@ -108,14 +170,16 @@ fn leaderboard_test() {
                 sc.leaderboard(1).get(1),
                 LeaderboardEntry {
                     user_id: 2,
-                    work_amount: managed_biguint!(200_000_000)
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(200_000_000)
                 }
             );
             assert_eq!(
                 sc.leaderboard(1).get(2),
                 LeaderboardEntry {
                     user_id: 1,
-                    work_amount: managed_biguint!(200_000_000)
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(200_000_000)
                 }
             );
         })
@@ -131,14 +195,16 @@ fn leaderboard_test() {
                 sc.leaderboard(1).get(1),
                 LeaderboardEntry {
                     user_id: 1,
-                    work_amount: managed_biguint!(300_000_000)
+                    raw_gas: managed_biguint!(300_000_000),
+                    score: managed_biguint!(300_000_000)
                 }
             );
             assert_eq!(
                 sc.leaderboard(1).get(2),
                 LeaderboardEntry {
                     user_id: 2,
-                    work_amount: managed_biguint!(200_000_000)
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(200_000_000)
                 }
             );
         })
@@ -164,14 +230,16 @@ fn dev_rewards_test() {
                 sc.leaderboard(1).get(1),
                 LeaderboardEntry {
                     user_id: 1,
-                    work_amount: managed_biguint!(300_000_000)
+                    raw_gas: managed_biguint!(300_000_000),
+                    score: managed_biguint!(300_000_000)
                 }
             );
             assert_eq!(
                 sc.leaderboard(1).get(2),
                 LeaderboardEntry {
                     user_id: 2,
-                    work_amount: managed_biguint!(100_000_000)
+                    raw_gas: managed_biguint!(100_000_000),
+                    score: managed_biguint!(100_000_000)
                 }
             );
         })
@@ -197,3 +265,3357 @@ fn dev_rewards_test() {
         )
         .assert_ok();
 }
+
+#[test]
+fn proof_of_work_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetPowConfig(managed_address!(&gas_burner), 8, 1)
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetWorkVerificationMode(
+                managed_address!(&gas_burner),
+                WorkVerificationMode::ProofOfWork,
+            )
+        })
+        .assert_ok();
+
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"invalid solution"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Invalid proof of work");
+
+    for _ in 0..3 {
+        let pow_solution = setup.find_pow_solution(&first_user);
+        setup
+            .b_mock
+            .execute_tx(
+                &first_user,
+                &setup.gas_burner_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.work(managed_buffer!(&pow_solution), OptionalValue::None);
+                },
+            )
+            .assert_ok();
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.submissions_for_week(1).get(), 3);
+            assert_eq!(sc.get_pow_difficulty(1), 8);
+
+            // 3 submissions with a target of 1, so difficulty goes up next week
+            assert_eq!(sc.get_pow_difficulty(2), 9);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(8);
+
+    let pow_solution = setup.find_pow_solution(&first_user);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(&pow_solution), OptionalValue::None);
+
+                assert_eq!(sc.pow_difficulty_for_week(2).get(), 9);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn session_key_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_user = setup.first_user.clone();
+    let delegate = setup.second_user.clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.register_session_key(
+                    managed_address!(&delegate),
+                    5,
+                    150_000_000,
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &delegate,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_for(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+
+                assert_eq!(sc.leaderboard(1).len(), 1);
+                assert_eq!(
+                    sc.leaderboard(1).get(1),
+                    LeaderboardEntry {
+                        user_id: 1,
+                        raw_gas: managed_biguint!(100_000_000),
+                        score: managed_biguint!(100_000_000)
+                    }
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(100));
+    setup
+        .b_mock
+        .check_esdt_balance(&delegate, GAS_BURNER_TOKEN_ID, &rust_biguint!(0));
+
+    // only 50M gas left in the allowance
+    setup
+        .b_mock
+        .execute_tx(
+            &delegate,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_for(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Gas allowance exceeded");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.revoke_session_key();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &delegate,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_for(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("No session key registered");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.register_session_key(
+                    managed_address!(&delegate),
+                    5,
+                    500_000_000,
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(5);
+
+    setup
+        .b_mock
+        .execute_tx(
+            &delegate,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_for(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Session key expired");
+}
+
+#[test]
+fn nonce_window_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::Some(2));
+                sc.work(managed_buffer!(b"signature"), OptionalValue::Some(5));
+
+                let user_address = managed_address!(&first_user);
+                assert_eq!(sc.get_user_nonce(user_address.clone()), 0);
+
+                let mut expected_used_nonces = MultiValueEncoded::new();
+                expected_used_nonces.push(2);
+                expected_used_nonces.push(5);
+                assert_eq!(
+                    sc.get_used_nonces_in_window(user_address.clone()),
+                    expected_used_nonces
+                );
+
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+                sc.work(managed_buffer!(b"signature"), OptionalValue::Some(1));
+                assert_eq!(sc.get_user_nonce(user_address.clone()), 3);
+
+                let mut expected_used_nonces = MultiValueEncoded::new();
+                expected_used_nonces.push(5);
+                assert_eq!(
+                    sc.get_used_nonces_in_window(user_address),
+                    expected_used_nonces
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::Some(5));
+            },
+        )
+        .assert_user_error("Nonce already used");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::Some(67));
+            },
+        )
+        .assert_user_error("Nonce outside of the window");
+}
+
+#[test]
+fn signers_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let signer = setup.signer.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::AddSigner(managed_address!(&gas_burner), managed_address!(&first_user))
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::RotateSigner(
+                managed_address!(&gas_burner),
+                2,
+                managed_address!(&second_user),
+            )
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetSignatureThreshold(managed_address!(&gas_burner), 2)
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            let mut expected_signers = MultiValueEncoded::new();
+            expected_signers.push((1, managed_address!(&signer)).into());
+            expected_signers.push((2, managed_address!(&second_user)).into());
+            assert_eq!(sc.get_signers(), expected_signers);
+        })
+        .assert_ok();
+
+    setup
+        .execute_owner_proposal(|| ProposalAction::RemoveSigner(managed_address!(&gas_burner), 1))
+        .assert_user_error("Not enough signers left for the threshold");
+
+    // threshold mode applies to session key registration
+    let delegate = setup.signer.clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut attestations = MultiValueEncoded::new();
+                attestations.push(signer_signature(1));
+                attestations.push(signer_signature(1));
+
+                sc.register_session_key(managed_address!(&delegate), 5, 1, attestations);
+            },
+        )
+        .assert_user_error("Duplicate signer");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut attestations = MultiValueEncoded::new();
+                attestations.push(signer_signature(1));
+
+                sc.register_session_key(managed_address!(&delegate), 5, 1, attestations);
+            },
+        )
+        .assert_user_error("Not enough signatures");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut attestations = MultiValueEncoded::new();
+                attestations.push(signer_signature(1));
+                attestations.push(signer_signature(2));
+
+                sc.register_session_key(managed_address!(&delegate), 5, 1, attestations);
+            },
+        )
+        .assert_ok();
+}
+
+fn signer_signature(key_id: u32) -> SignerSignature<DebugApi> {
+    SignerSignature {
+        key_id,
+        signature: ManagedByteArray::new_from_bytes(&[0u8; 64]),
+    }
+}
+
+#[test]
+fn signed_message_layout_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_user = setup.first_user.clone();
+    let gas_burner_address = setup.gas_burner_wrapper.address_ref().clone();
+    let offchain_message = build_work_message(
+        b"D",
+        gas_burner_address.as_array(),
+        SignedEndpoint::WorkFor,
+        2,
+        first_user.as_array(),
+        5,
+        1_000,
+        Some(b"kind"),
+    );
+    assert!(offchain_message.starts_with(SIGNED_MESSAGE_PREFIX));
+
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |_| {
+            let chain_id: ManagedBuffer<DebugApi> = managed_buffer!(b"D");
+            let work_kind: ManagedBuffer<DebugApi> = managed_buffer!(b"kind");
+            let contract_address: ManagedAddress<DebugApi> = managed_address!(&gas_burner_address);
+            let user_address: ManagedAddress<DebugApi> = managed_address!(&first_user);
+            let mut onchain_message = ManagedBuffer::<DebugApi>::new();
+            encode_message_header(
+                &mut onchain_message,
+                &chain_id,
+                &contract_address,
+                SignedEndpoint::WorkFor,
+                2,
+            );
+            encode_work_message_body(
+                &mut onchain_message,
+                &user_address,
+                5,
+                1_000,
+                &Some(work_kind),
+            );
+
+            assert_eq!(
+                onchain_message.to_boxed_bytes().as_slice(),
+                offchain_message
+            );
+        })
+        .assert_ok();
+
+    // the chain id is set on deploy, without a separate setChainId
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.chain_id().get(), managed_buffer!(CHAIN_ID));
+
+                let user_address: ManagedAddress<DebugApi> = managed_address!(&first_user);
+                let mut message_body = ManagedBuffer::new();
+                encode_work_message_body(
+                    &mut message_body,
+                    &user_address,
+                    0,
+                    1_000,
+                    &None::<ManagedBuffer<DebugApi>>,
+                );
+                sc.verify_signer_signature(
+                    SignedEndpoint::Work,
+                    &message_body,
+                    &signer_signature(1),
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn governance_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_admin = setup.first_user.clone();
+    let second_admin = setup.second_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    for role in [
+        AdminRole::Pauser,
+        AdminRole::Configurator,
+        AdminRole::Treasurer,
+    ] {
+        setup
+            .execute_owner_proposal(|| {
+                ProposalAction::GrantRole(managed_address!(&first_admin), role)
+            })
+            .assert_ok();
+    }
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::GrantRole(managed_address!(&second_admin), AdminRole::Pauser)
+        })
+        .assert_ok();
+    // there are only 2 configurators, so a quorum of 3 is unreachable
+    setup
+        .execute_owner_proposal(|| ProposalAction::SetApprovalQuorum(3))
+        .assert_user_error("Not enough admins for the quorum");
+    setup
+        .execute_owner_proposal(|| ProposalAction::SetTimelock(100))
+        .assert_ok();
+
+    // the timelock now applies to every proposal
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let proposal_id = sc.propose(ProposalAction::SetTimelock(0));
+                sc.execute_proposal(proposal_id);
+            },
+        )
+        .assert_user_error("Timelock not expired");
+
+    // pausing is instant for any pauser
+    setup
+        .b_mock
+        .execute_tx(
+            &second_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause_gas_burner(managed_address!(&gas_burner));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &second_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose(ProposalAction::SetTimelock(0));
+            },
+        )
+        .assert_user_error("Caller does not have the required role");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &second_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let proposal_id = sc.propose(ProposalAction::UnpauseGasBurner(managed_address!(
+                    &gas_burner
+                )));
+                assert_eq!(proposal_id, 9);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(9);
+            },
+        )
+        .assert_user_error("Timelock not expired");
+
+    // only the proposer may cancel
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.cancel_proposal(9);
+            },
+        )
+        .assert_user_error("Only the proposer may cancel");
+
+    // role changes need the role being granted
+    setup
+        .b_mock
+        .execute_tx(
+            &second_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose(ProposalAction::GrantRole(
+                    managed_address!(&second_admin),
+                    AdminRole::Treasurer,
+                ));
+            },
+        )
+        .assert_user_error("Caller does not have the required role");
+
+    setup.b_mock.set_block_timestamp(DEFAULT_TIMELOCK + 100);
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(9);
+
+                assert!(sc.proposal(9).is_empty());
+                assert!(sc
+                    .role_members(AdminRole::Pauser)
+                    .contains(&managed_address!(&second_admin)));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert!(!sc.is_paused());
+        })
+        .assert_ok();
+
+    // approvals are counted again against the quorum at execution
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(
+                    sc.propose(ProposalAction::RemoveGasBurner(managed_address!(
+                        &gas_burner
+                    ))),
+                    10
+                );
+                assert_eq!(sc.propose(ProposalAction::SetApprovalQuorum(2)), 11);
+            },
+        )
+        .assert_ok();
+    setup.b_mock.set_block_timestamp(DEFAULT_TIMELOCK + 200);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(11);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(10);
+            },
+        )
+        .assert_user_error("Not enough approvals");
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve(10);
+                sc.execute_proposal(10);
+
+                assert!(!sc.gas_burners().contains(&managed_address!(&gas_burner)));
+            },
+        )
+        .assert_ok();
+
+    // proposals expire once the lifetime is over
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.propose(ProposalAction::SetTimelock(0)), 12);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(DEFAULT_TIMELOCK + 300 + PROPOSAL_LIFETIME);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve(12);
+            },
+        )
+        .assert_user_error("Proposal expired");
+}
+
+#[test]
+fn proposal_approval_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_admin = setup.first_user.clone();
+    let second_admin = setup.second_user.clone();
+    let outsider = setup.b_mock.create_user_account(&rust_biguint!(0));
+    for role in [
+        AdminRole::Pauser,
+        AdminRole::Configurator,
+        AdminRole::Treasurer,
+    ] {
+        setup
+            .execute_owner_proposal(|| {
+                ProposalAction::GrantRole(managed_address!(&first_admin), role)
+            })
+            .assert_ok();
+    }
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::GrantRole(managed_address!(&second_admin), AdminRole::Configurator)
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| ProposalAction::SetApprovalQuorum(2))
+        .assert_ok();
+
+    // a second approver reaches the quorum
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            assert_eq!(sc.propose(ProposalAction::SetTimelock(100)), 9);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(9);
+            },
+        )
+        .assert_user_error("Not enough approvals");
+    setup
+        .b_mock
+        .execute_tx(
+            &second_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve(9);
+                sc.execute_proposal(9);
+
+                assert_eq!(sc.timelock().get(), 100);
+            },
+        )
+        .assert_ok();
+
+    // duplicate approvals and approvals without the role are rejected
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            assert_eq!(sc.propose(ProposalAction::SetTimelock(50)), 10);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.approve(10);
+        })
+        .assert_user_error("Already approved");
+    setup
+        .b_mock
+        .execute_tx(
+            &outsider,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve(10);
+            },
+        )
+        .assert_user_error("Caller does not have the required role");
+
+    // the timelock runs from the approval reaching the quorum
+    setup.b_mock.set_block_timestamp(DEFAULT_TIMELOCK + 10);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_admin,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve(10);
+
+                assert_eq!(
+                    sc.proposal(10).get().executable_after,
+                    Some(DEFAULT_TIMELOCK + 110)
+                );
+            },
+        )
+        .assert_ok();
+    setup.b_mock.set_block_timestamp(DEFAULT_TIMELOCK + 109);
+    setup
+        .b_mock
+        .execute_tx(
+            &outsider,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(10);
+            },
+        )
+        .assert_user_error("Timelock not expired");
+    setup.b_mock.set_block_timestamp(DEFAULT_TIMELOCK + 110);
+    setup
+        .b_mock
+        .execute_tx(
+            &outsider,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_proposal(10);
+
+                assert_eq!(sc.timelock().get(), 50);
+                assert!(sc.proposal(10).is_empty());
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn gas_burner_registry_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let signer = setup.signer.clone();
+    let second_gas_burner_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(setup.owner_sc_wrapper.address_ref()),
+        gas_burner::contract_obj,
+        "second gas burner",
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &owner,
+            &second_gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init(
+                    managed_address!(setup.owner_sc_wrapper.address_ref()),
+                    managed_address!(&signer),
+                    managed_buffer!(CHAIN_ID),
+                    OptionalValue::Some(managed_token_id!(GAS_BURNER_TOKEN_ID)),
+                );
+            },
+        )
+        .assert_ok();
+
+    let first_gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    let second_gas_burner = second_gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::AddGasBurner(managed_address!(&second_gas_burner))
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::AddGasBurner(managed_address!(&second_gas_burner))
+        })
+        .assert_user_error("Gas burner already registered");
+
+    setup
+        .execute_owner_proposal(|| ProposalAction::UnpauseAllGasBurners)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&second_gas_burner_wrapper, |sc| {
+            assert!(!sc.is_paused());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.pause_all_gas_burners();
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert!(sc.is_paused());
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&second_gas_burner_wrapper, |sc| {
+            assert!(sc.is_paused());
+        })
+        .assert_ok();
+
+    // each gas burner only receives its own dev rewards
+    setup
+        .b_mock
+        .set_developer_rewards(&first_gas_burner, rust_biguint!(100_000));
+    setup
+        .b_mock
+        .set_developer_rewards(&second_gas_burner, rust_biguint!(30_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &second_gas_burner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let dev_rewards = sc.claim_dev_rewards();
+                assert_eq!(dev_rewards, managed_biguint!(30_000));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&second_gas_burner, &rust_biguint!(30_000));
+    setup
+        .b_mock
+        .check_egld_balance(&first_gas_burner, &rust_biguint!(0));
+    setup
+        .b_mock
+        .execute_query(&setup.owner_sc_wrapper, |sc| {
+            assert_eq!(
+                sc.dev_rewards_claimed(&managed_address!(&second_gas_burner))
+                    .get(),
+                managed_biguint!(30_000)
+            );
+            assert_eq!(
+                sc.dev_rewards_claimed(&managed_address!(&first_gas_burner))
+                    .get(),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::RemoveGasBurner(managed_address!(&second_gas_burner))
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &second_gas_burner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_dev_rewards();
+            },
+        )
+        .assert_user_error("Unknown gas burner");
+}
+
+#[test]
+fn reserve_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    let owner_sc = setup.owner_sc_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_egld_balance(&owner, &rust_biguint!(50_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &owner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(50_000),
+            |sc| {
+                sc.deposit_reserve();
+            },
+        )
+        .assert_ok();
+
+    // the reserve is not forwarded along with the dev rewards
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &gas_burner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let dev_rewards = sc.claim_dev_rewards();
+                assert_eq!(dev_rewards, managed_biguint!(100_000));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&owner_sc, &rust_biguint!(50_000));
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose(ProposalAction::WithdrawReserve(
+                    managed_biguint!(10_000),
+                    managed_address!(&first_user),
+                ));
+            },
+        )
+        .assert_user_error("Caller does not have the required role");
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::WithdrawReserve(managed_biguint!(60_000), managed_address!(&owner))
+        })
+        .assert_user_error("Not enough EGLD in the reserve");
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::WithdrawReserve(managed_biguint!(10_000), managed_address!(&first_user))
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.owner_sc_wrapper, |sc| {
+            assert_eq!(sc.reserve().get(), managed_biguint!(40_000));
+            assert_eq!(
+                sc.dev_rewards_claimed(&managed_address!(&gas_burner)).get(),
+                managed_biguint!(100_000)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&first_user, &rust_biguint!(10_000));
+}
+
+#[test]
+fn dev_rewards_split_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let treasury = setup.first_user.clone();
+    let buyback = setup.second_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetDevRewardsSplit(DevRewardsSplit {
+                treasury: managed_address!(&treasury),
+                treasury_percentage: 8_000,
+                buyback: managed_address!(&buyback),
+                buyback_percentage: 3_000,
+            })
+        })
+        .assert_user_error("Invalid percentages");
+    // would wrap around to exactly the maximum
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetDevRewardsSplit(DevRewardsSplit {
+                treasury: managed_address!(&treasury),
+                treasury_percentage: u32::MAX,
+                buyback: managed_address!(&buyback),
+                buyback_percentage: 10_001,
+            })
+        })
+        .assert_user_error("Invalid percentages");
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetDevRewardsSplit(DevRewardsSplit {
+                treasury: managed_address!(&treasury),
+                treasury_percentage: 1_500,
+                buyback: managed_address!(&buyback),
+                buyback_percentage: 500,
+            })
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &gas_burner,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let prize_pool_amount = sc.claim_dev_rewards();
+                assert_eq!(prize_pool_amount, managed_biguint!(80_000));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .check_egld_balance(&gas_burner, &rust_biguint!(80_000));
+    setup
+        .b_mock
+        .check_egld_balance(&treasury, &rust_biguint!(15_000));
+    setup
+        .b_mock
+        .check_egld_balance(&buyback, &rust_biguint!(5_000));
+    setup
+        .b_mock
+        .execute_query(&setup.owner_sc_wrapper, |sc| {
+            assert_eq!(sc.last_dev_rewards_claim_id().get(), 1);
+            assert_eq!(
+                sc.dev_rewards_claim(1).get(),
+                DevRewardsClaim {
+                    gas_burner: managed_address!(&gas_burner),
+                    prize_pool_amount: managed_biguint!(80_000),
+                    treasury_amount: managed_biguint!(15_000),
+                    buyback_amount: managed_biguint!(5_000),
+                }
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gas_burner_admin_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let configurator = setup.first_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            let mut arguments = ManagedVec::new();
+            arguments.push(managed_buffer!(&[4u8]));
+            ProposalAction::CallGasBurner(
+                managed_address!(&gas_burner),
+                managed_buffer!(b"setClaimWindow"),
+                arguments,
+            )
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.claim_window_weeks().get(), 4);
+        })
+        .assert_ok();
+
+    // endpoints with a dedicated action keep their own role
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::GrantRole(managed_address!(&configurator), AdminRole::Configurator)
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &configurator,
+            &setup.owner_sc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut arguments = ManagedVec::new();
+                arguments.push(managed_buffer!(b"EGLD"));
+                arguments.push(managed_buffer!(&[0u8]));
+                arguments.push(managed_buffer!(&[1u8]));
+                arguments.push(managed_buffer!(configurator.as_bytes()));
+                let proposal_id = sc.propose(ProposalAction::CallGasBurner(
+                    managed_address!(&gas_burner),
+                    managed_buffer!(b"recoverFunds"),
+                    arguments,
+                ));
+                sc.execute_proposal(proposal_id);
+            },
+        )
+        .assert_user_error("Endpoint has a dedicated action");
+
+    // an upgrade proposal is only removed once its callback confirms the upgrade
+    let upgrade = || {
+        ProposalAction::UpgradeGasBurner(GasBurnerUpgrade {
+            gas_burner: managed_address!(&gas_burner),
+            source: UpgradeSource::SourceAddress(managed_address!(&gas_burner)),
+            code_metadata: CodeMetadata::UPGRADEABLE,
+            arguments: ManagedVec::new(),
+        })
+    };
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            let proposal_id = sc.propose(upgrade());
+            sc.proposal_in_progress(proposal_id).set(true);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.execute_proposal(sc.last_proposal_id().get());
+        })
+        .assert_user_error("Proposal in progress");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.cancel_proposal(sc.last_proposal_id().get());
+        })
+        .assert_user_error("Proposal in progress");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            let proposal_id = sc.last_proposal_id().get();
+            sc.async_proposal_callback(
+                proposal_id,
+                ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                    err_code: 10,
+                    err_msg: managed_buffer!(b"upgrade failed"),
+                }),
+            );
+
+            assert!(!sc.proposal(proposal_id).is_empty());
+            assert!(!sc.proposal_in_progress(proposal_id).get());
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            let proposal_id = sc.last_proposal_id().get();
+            sc.execute_proposal(proposal_id);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.owner_sc_wrapper, |sc| {
+            assert!(sc.proposal(sc.last_proposal_id().get()).is_empty());
+        })
+        .assert_ok();
+
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::TransferGasBurnerOwnership(
+                managed_address!(&gas_burner),
+                managed_address!(&owner),
+            )
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(&owner)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.owner_sc_wrapper, |sc| {
+            assert!(sc.gas_burners().is_empty());
+        })
+        .assert_ok();
+
+    // the gas burner is no longer managed by the owner SC
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::UpgradeGasBurner(GasBurnerUpgrade {
+                gas_burner: managed_address!(&gas_burner),
+                source: UpgradeSource::SourceAddress(managed_address!(&gas_burner)),
+                code_metadata: CodeMetadata::UPGRADEABLE,
+                arguments: ManagedVec::new(),
+            })
+        })
+        .assert_user_error("Unknown gas burner");
+}
+
+#[test]
+fn recovery_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup.b_mock.set_block_epoch(8);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_rewards(1);
+            },
+        )
+        .assert_ok();
+
+    // the second user's rewards are still owed, only the mistaken transfer is recoverable
+    setup
+        .b_mock
+        .set_egld_balance(&gas_burner, &rust_biguint!(35_000));
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(25_000));
+            assert_eq!(
+                sc.get_recoverable_balance(EgldOrEsdtTokenIdentifier::egld(), 0),
+                managed_biguint!(10_000)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::RecoverGasBurnerFunds(
+                managed_address!(&gas_burner),
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(15_000),
+                managed_address!(&owner),
+            )
+        })
+        .assert_user_error("Amount exceeds the recoverable balance");
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::RecoverGasBurnerFunds(
+                managed_address!(&gas_burner),
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(10_000),
+                managed_address!(&owner),
+            )
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&owner, &rust_biguint!(10_000));
+    setup
+        .b_mock
+        .check_egld_balance(&gas_burner, &rust_biguint!(25_000));
+}
+
+#[test]
+fn pause_flags_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup.work_user(&first_user);
+
+    for flag in [
+        OwnerScPauseFlag::Work,
+        OwnerScPauseFlag::Claims,
+        OwnerScPauseFlag::DevRewardsCollection,
+    ] {
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+                sc.pause_gas_burner_flag(managed_address!(&gas_burner), flag);
+            })
+            .assert_ok();
+    }
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_pause_status(),
+                PauseStatus {
+                    global: false,
+                    work: true,
+                    claims: true,
+                    dev_rewards_collection: true,
+                    registration: false,
+                }
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Work is paused");
+
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup.b_mock.set_block_epoch(8);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_rewards(1);
+            },
+        )
+        .assert_user_error("Claims are paused");
+
+    // the previous week is not claimable until its dev rewards are collected
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::UnpauseGasBurnerFlag(
+                managed_address!(&gas_burner),
+                OwnerScPauseFlag::Claims,
+            )
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let rewards = sc.claim_rewards(1);
+                assert_eq!(rewards, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::UnpauseGasBurnerFlag(
+                managed_address!(&gas_burner),
+                OwnerScPauseFlag::DevRewardsCollection,
+            )
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let rewards = sc.claim_rewards(1);
+                assert_eq!(rewards, managed_biguint!(100_000));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn storage_migration_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+
+    // storage version 1 layout
+    setup.b_mock.set_block_epoch(7 * 29);
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.storage_version().clear();
+                sc.claim_window_weeks().clear();
+                sc.chain_id().clear();
+                sc.legacy_signer().set(managed_address!(&second_user));
+                sc.legacy_user_claimed_for_week(1).set(true);
+                sc.legacy_user_claimed_for_week(22).set(true);
+                sc.outstanding_rewards().clear();
+                sc.total_rewards_week(2).set(managed_biguint!(5_000));
+                sc.total_rewards_week(22).set(managed_biguint!(30_000));
+
+                let mut legacy_leaderboard = sc.legacy_leaderboard(1);
+                let mut other_legacy_leaderboard = sc.legacy_leaderboard(22);
+                for (user_id, work_amount) in [(1, 100_000_000u64), (2, 50_000_000)] {
+                    let legacy_entry = LegacyLeaderboardEntry {
+                        user_id,
+                        work_amount: managed_biguint!(work_amount),
+                    };
+                    legacy_leaderboard.set(user_id as usize, &legacy_entry);
+                    other_legacy_leaderboard.push(&legacy_entry);
+                    sc.user_index_in_leaderboard(user_id, 22)
+                        .set(user_id as usize);
+                }
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            let gas_burner = sc.gas_burners().get_by_index(1);
+            let _ = sc.gas_burners().swap_remove(&gas_burner);
+            sc.legacy_gas_burner().set(gas_burner);
+            sc.approval_quorum().clear();
+            sc.timelock().clear();
+            sc.storage_version().clear();
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.owner_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade();
+
+            assert_eq!(sc.storage_version().get(), 2);
+            assert!(sc.legacy_gas_burner().is_empty());
+            assert_eq!(sc.gas_burners().len(), 1);
+            assert_eq!(sc.approval_quorum().get(), 1);
+            assert_eq!(sc.timelock().get(), DEFAULT_TIMELOCK);
+
+            sc.timelock().clear();
+        })
+        .assert_ok();
+
+    // the chain id was not set before
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.upgrade(OptionalValue::None);
+            },
+        )
+        .assert_user_error("Chain id not set");
+
+    // the first 20 weeks are migrated on upgrade
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.upgrade(OptionalValue::Some(managed_buffer!(CHAIN_ID)));
+
+                assert_eq!(sc.chain_id().get(), managed_buffer!(CHAIN_ID));
+
+                assert_eq!(sc.storage_version().get(), 1);
+                assert_eq!(sc.migration_cursor().get(), 21);
+                assert_eq!(sc.claim_window_start_week().get(), 30);
+                assert_eq!(sc.get_first_claimable_week(30), 1);
+                assert!(sc.legacy_signer().is_empty());
+                // the legacy flag does not tell who claimed
+                assert!(!sc.user_claimed_for_week(1, 1).get());
+                assert!(!sc.user_claimed_for_week(2, 1).get());
+                assert!(sc.legacy_user_claimed_for_week(1).get());
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_rewards(1);
+            },
+        )
+        .assert_user_error("Storage migration in progress");
+
+    // unclaimed rewards are not fully tracked yet
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.recover_funds(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(1),
+                    managed_address!(&owner),
+                );
+            },
+        )
+        .assert_user_error("Storage migration in progress");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(!sc.continue_migration(5));
+
+                // the remaining 4 weeks of version 2, then the first 6 weeks of version 3
+                assert!(!sc.continue_migration(10));
+                assert_eq!(sc.storage_version().get(), 2);
+                assert_eq!(sc.migration_cursor().get(), 7);
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(35_000));
+                assert_eq!(sc.signers().len(), 2);
+                assert_eq!(
+                    sc.leaderboard(1).get(2),
+                    LeaderboardEntry {
+                        user_id: 2,
+                        raw_gas: managed_biguint!(50_000_000),
+                        score: managed_biguint!(50_000_000),
+                    }
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Storage migration in progress");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(sc.continue_migration(30));
+
+                assert_eq!(sc.storage_version().get(), 3);
+                assert!(sc.migration_cursor().is_empty());
+            },
+        )
+        .assert_ok();
+    setup.work_user(&first_user);
+
+    // the week stays unclaimable until the owner tells who claimed under the legacy layout
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_egld_balance(&gas_burner, &rust_biguint!(15_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let rewards = sc.claim_rewards(22);
+                assert_eq!(rewards, managed_biguint!(0));
+                assert!(!sc.user_claimed_for_week(2, 22).get());
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.resolve_legacy_claim(22, managed_address!(&first_user));
+
+                assert!(sc.user_claimed_for_week(1, 22).get());
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(15_000));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let rewards = sc.claim_rewards(22);
+                assert_eq!(rewards, managed_biguint!(10_000));
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(5_000));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn prune_week_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup.b_mock.set_block_epoch(8);
+    setup.work_user(&first_user);
+
+    for user in [&first_user, &second_user] {
+        setup
+            .b_mock
+            .execute_tx(user, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+                sc.prune_week(1, 10);
+            })
+            .assert_user_error("Week still has rewards to claim");
+        setup
+            .b_mock
+            .execute_tx(user, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+                sc.claim_rewards(1);
+            })
+            .assert_ok();
+    }
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(!sc.prune_week(1, 1));
+                assert!(sc.prune_week(1, 1));
+
+                assert!(sc.leaderboard(1).is_empty());
+                assert_eq!(sc.user_index_in_leaderboard(1, 1).get(), 0);
+                assert!(!sc.user_claimed_for_week(2, 1).get());
+                assert!(sc.total_rewards_week(1).is_empty());
+
+                let mut expected_hash: ManagedByteArray<DebugApi, 32> =
+                    ManagedByteArray::new_from_bytes(&[0u8; 32]);
+                let mut winners = ManagedVec::new();
+                for entry in [
+                    LeaderboardEntry {
+                        user_id: 1,
+                        raw_gas: managed_biguint!(300_000_000),
+                        score: managed_biguint!(300_000_000),
+                    },
+                    LeaderboardEntry {
+                        user_id: 2,
+                        raw_gas: managed_biguint!(100_000_000),
+                        score: managed_biguint!(100_000_000),
+                    },
+                ] {
+                    winners.push(entry);
+                }
+                for entry in winners.iter().rev() {
+                    let mut hash_input = expected_hash.as_managed_buffer().clone();
+                    let _ = entry.top_encode(&mut hash_input);
+                    expected_hash = sc.crypto().keccak256(hash_input);
+                }
+
+                assert_eq!(
+                    sc.week_summary(1).get(),
+                    WeekSummary {
+                        winners,
+                        nr_participants: 2,
+                        total_top_work: managed_biguint!(400_000_000),
+                        total_rewards: managed_biguint!(100_000),
+                        rewards_paid: managed_biguint!(100_000),
+                        leaderboard_hash: expected_hash,
+                    }
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.prune_week(1, 1);
+            },
+        )
+        .assert_user_error("Week already pruned");
+
+    // week 2 was never claimed, and stays claimable as long as the window allows
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_window(20);
+            },
+        )
+        .assert_ok();
+    setup.b_mock.set_block_epoch(7 * 14);
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.prune_week(2, 10);
+            },
+        )
+        .assert_user_error("Week still has rewards to claim");
+
+    // a shorter window counts from the current week
+    setup
+        .b_mock
+        .execute_tx(
+            setup.owner_sc_wrapper.address_ref(),
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_window(12);
+
+                assert_eq!(sc.claim_window_start_week().get(), 15);
+                assert_eq!(sc.get_first_claimable_week(27), 1);
+                assert_eq!(sc.get_first_claimable_week(28), 16);
+            },
+        )
+        .assert_ok();
+    setup.b_mock.set_block_epoch(7 * 27);
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(sc.prune_week(2, 10));
+                assert_eq!(sc.week_summary(2).get().nr_participants, 1);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn issue_token_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let signer = setup.signer.clone();
+    let first_user = setup.first_user.clone();
+    let new_gas_burner_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        gas_burner::contract_obj,
+        "new gas burner",
+    );
+    let new_gas_burner = new_gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &new_gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.init(
+                managed_address!(setup.owner_sc_wrapper.address_ref()),
+                managed_address!(&signer),
+                managed_buffer!(CHAIN_ID),
+                OptionalValue::None,
+            );
+            sc.unpause_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &new_gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Token not issued");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &new_gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.issue_token_callback(ManagedAsyncCallResult::Ok(managed_token_id!(
+                NEW_GAS_BURNER_TOKEN_ID
+            )));
+            assert_eq!(
+                sc.token().get_token_id(),
+                managed_token_id!(NEW_GAS_BURNER_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &new_gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Mint role not set");
+
+    setup.b_mock.set_esdt_local_roles(
+        &new_gas_burner,
+        NEW_GAS_BURNER_TOKEN_ID,
+        &[EsdtLocalRole::Mint],
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &new_gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, NEW_GAS_BURNER_TOKEN_ID, &rust_biguint!(100));
+
+    // the issue cost is paid from the owner SC's reserve
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::IssueGasBurnerToken(GasBurnerTokenIssue {
+                gas_burner: managed_address!(&gas_burner),
+                token_kind: GasBurnerTokenKind::Liquid,
+                token_display_name: managed_buffer!(b"GasToken"),
+                token_ticker: managed_buffer!(b"GAS"),
+                num_decimals: 18,
+                issue_cost: managed_biguint!(50_000_000_000_000_000),
+            })
+        })
+        .assert_user_error("Not enough EGLD in the reserve");
+}
+
+#[test]
+fn locked_rewards_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.issue_locked_token_callback(ManagedAsyncCallResult::Ok(managed_token_id!(
+                LOCKED_TOKEN_ID
+            )));
+        })
+        .assert_ok();
+    setup
+        .execute_owner_proposal(|| {
+            ProposalAction::SetMintMode(
+                managed_address!(&gas_burner),
+                MintMode::Locked(LockSchedule {
+                    lock_epochs: 2,
+                    vesting_epochs: 4,
+                }),
+            )
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work(managed_buffer!(b"signature"), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Locked token roles not set");
+
+    setup.b_mock.set_esdt_local_roles(
+        &gas_burner,
+        LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
+    setup.work_user(&first_user);
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(100),
+        Some(&LockedTokenAttributes {
+            vesting_start_epoch: 2,
+            vesting_end_epoch: 6,
+        }),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(0));
+
+    setup.b_mock.set_block_epoch(2);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(100),
+            |sc| {
+                sc.unlock();
+            },
+        )
+        .assert_user_error("Nothing to unlock yet");
+
+    // half of the vesting period passed
+    setup.b_mock.set_block_epoch(4);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(100),
+            |sc| {
+                let unlocked = sc.unlock();
+                assert_eq!(unlocked.amount, managed_biguint!(50));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(50));
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(50),
+        Some(&LockedTokenAttributes {
+            vesting_start_epoch: 4,
+            vesting_end_epoch: 6,
+        }),
+    );
+
+    setup.b_mock.set_block_epoch(6);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            LOCKED_TOKEN_ID,
+            2,
+            &rust_biguint!(50),
+            |sc| {
+                sc.unlock();
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(100));
+    setup.b_mock.check_nft_balance::<LockedTokenAttributes>(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(0),
+        None,
+    );
+}
+
+#[test]
+fn staking_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            let mut tiers = MultiValueEncoded::new();
+            tiers.push(MultiValue2::from((managed_biguint!(100), 20_000)));
+            tiers.push(MultiValue2::from((managed_biguint!(50), 15_000)));
+            sc.set_multiplier_tiers(tiers);
+        })
+        .assert_user_error("Tiers not sorted by stake");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            let mut tiers = MultiValueEncoded::new();
+            tiers.push(MultiValue2::from((managed_biguint!(50), 15_000)));
+            tiers.push(MultiValue2::from((managed_biguint!(100), 20_000)));
+            sc.set_multiplier_tiers(tiers);
+            sc.set_unbond_epochs(3);
+        })
+        .assert_ok();
+
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            GAS_BURNER_TOKEN_ID,
+            0,
+            &rust_biguint!(50),
+            |sc| {
+                sc.stake();
+
+                assert_eq!(
+                    sc.get_work_multiplier(managed_address!(&first_user)),
+                    BASE_MULTIPLIER
+                );
+            },
+        )
+        .assert_ok();
+
+    // the stake only counts once held since the start of the week
+    setup.work_user(&first_user);
+    setup.b_mock.set_block_epoch(7);
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_work_multiplier(managed_address!(&first_user)),
+                15_000
+            );
+            assert_eq!(
+                sc.leaderboard(1).get(1).score,
+                managed_biguint!(200_000_000)
+            );
+            assert_eq!(
+                sc.leaderboard(2).get(1).score,
+                managed_biguint!(150_000_000)
+            );
+            assert_eq!(sc.total_staked().get(), managed_biguint!(50));
+            assert_eq!(
+                sc.get_recoverable_balance(
+                    EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(GAS_BURNER_TOKEN_ID)),
+                    0
+                ),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unstake(managed_biguint!(50));
+
+                assert_eq!(
+                    sc.get_work_multiplier(managed_address!(&first_user)),
+                    BASE_MULTIPLIER
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unbond();
+            },
+        )
+        .assert_user_error("Unbond period not over");
+
+    setup.b_mock.set_block_epoch(10);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let payment = sc.unbond();
+                assert_eq!(payment.amount, managed_biguint!(50));
+                assert_eq!(sc.total_staked().get(), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(300));
+}
+
+#[test]
+fn staking_pool_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_staking_pool_percentage(2_000);
+        })
+        .assert_user_error("Unbond period required by the staking pool");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_unbond_epochs(1);
+            sc.set_staking_pool_percentage(2_000);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_unbond_epochs(0);
+        })
+        .assert_user_error("Unbond period required by the staking pool");
+
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+    for (user, stake_amount) in [(&first_user, 75u64), (&second_user, 25u64)] {
+        setup
+            .b_mock
+            .execute_esdt_transfer(
+                user,
+                &setup.gas_burner_wrapper,
+                GAS_BURNER_TOKEN_ID,
+                0,
+                &rust_biguint!(stake_amount),
+                |sc| {
+                    sc.stake();
+                },
+            )
+            .assert_ok();
+    }
+
+    // the stake was not held for the whole first week, so it earns nothing from it
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup.b_mock.set_block_epoch(7);
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let leaderboard_rewards = sc.claim_rewards(1);
+                assert_eq!(leaderboard_rewards, managed_biguint!(50_000));
+                assert_eq!(sc.total_rewards_week(1).get(), managed_biguint!(100_000));
+                assert_eq!(sc.total_active_stake().get(), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    // staking right before the rewards are distributed earns nothing either
+    setup.b_mock.set_block_epoch(14);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            GAS_BURNER_TOKEN_ID,
+            0,
+            &rust_biguint!(100),
+            |sc| {
+                sc.stake();
+            },
+        )
+        .assert_ok();
+
+    // 20% of the dev rewards go to the stakers, the rest to the leaderboard
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let leaderboard_rewards = sc.claim_rewards(2);
+                assert_eq!(leaderboard_rewards, managed_biguint!(40_000));
+                assert_eq!(sc.total_rewards_week(2).get(), managed_biguint!(80_000));
+                assert_eq!(sc.total_active_stake().get(), managed_biguint!(100));
+
+                assert_eq!(
+                    sc.get_claimable_staking_rewards(managed_address!(&second_user)),
+                    managed_biguint!(5_000)
+                );
+                let staking_rewards = sc.claim_staking_rewards();
+                assert_eq!(staking_rewards, managed_biguint!(15_000));
+                assert_eq!(
+                    sc.get_claimable_staking_rewards(managed_address!(&first_user)),
+                    managed_biguint!(0)
+                );
+                assert_eq!(
+                    sc.staking_rewards_outstanding().get(),
+                    managed_biguint!(5_000)
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&first_user, &rust_biguint!(105_000));
+
+    // the pending stake is unstaked first
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unstake(managed_biguint!(110));
+
+                assert_eq!(sc.total_active_stake().get(), managed_biguint!(90));
+                assert!(sc.pending_stake().get().is_empty());
+                assert_eq!(
+                    sc.unclaimed_staking_rewards(&managed_address!(&second_user))
+                        .get(),
+                    managed_biguint!(5_000)
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn boost_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_boost_config(BoostConfig {
+                multiplier_bonus: 10_000,
+                duration_epochs: 2,
+                gas_price: managed_biguint!(50),
+                egld_price: managed_biguint!(1_000),
+                max_active_boosts: 2,
+            });
+        })
+        .assert_ok();
+
+    setup.b_mock.set_esdt_local_roles(
+        setup.gas_burner_wrapper.address_ref(),
+        GAS_BURNER_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            GAS_BURNER_TOKEN_ID,
+            0,
+            &rust_biguint!(40),
+            |sc| {
+                sc.boost();
+            },
+        )
+        .assert_user_error("Invalid payment amount");
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            GAS_BURNER_TOKEN_ID,
+            0,
+            &rust_biguint!(50),
+            |sc| {
+                sc.boost();
+
+                assert_eq!(
+                    sc.get_boost_multiplier(managed_address!(&first_user)),
+                    20_000
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(50));
+
+    // EGLD waits for the next dev rewards collection
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_guild_config(GuildConfig {
+                max_members: 2,
+                switch_cooldown_epochs: 3,
+                prize_pool_percentage: 5_000,
+            });
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.create_guild(managed_buffer!(b"alpha")), 1);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .set_egld_balance(&first_user, &rust_biguint!(2_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.boost();
+
+                assert_eq!(sc.get_active_boosts(managed_address!(&first_user)).len(), 2);
+                assert_eq!(
+                    sc.get_boost_multiplier(managed_address!(&first_user)),
+                    30_000
+                );
+                assert_eq!(sc.pending_boost_rewards().get(), managed_biguint!(1_000));
+                assert!(sc.total_rewards_week(1).is_empty());
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.boost();
+            },
+        )
+        .assert_user_error("Too many active boosts");
+
+    // 100_000_000 gas at 1x, then 100_000_000 gas at 3x
+    setup.work_user(&first_user);
+    setup.b_mock.set_block_epoch(2);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.leaderboard(1).get(1).score,
+                managed_biguint!(400_000_000)
+            );
+            assert_eq!(
+                sc.leaderboard(1).get(1).raw_gas,
+                managed_biguint!(200_000_000)
+            );
+            assert!(sc
+                .get_active_boosts(managed_address!(&first_user))
+                .is_empty());
+            assert_eq!(
+                sc.get_boost_multiplier(managed_address!(&first_user)),
+                BASE_MULTIPLIER
+            );
+        })
+        .assert_ok();
+
+    // the stacked bonuses are capped
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_boost_config(BoostConfig {
+                multiplier_bonus: 60_000,
+                duration_epochs: 2,
+                gas_price: managed_biguint!(0),
+                egld_price: managed_biguint!(1_000),
+                max_active_boosts: 11,
+            });
+        })
+        .assert_user_error("Invalid max active boosts");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_boost_config(BoostConfig {
+                multiplier_bonus: 60_000,
+                duration_epochs: 2,
+                gas_price: managed_biguint!(0),
+                egld_price: managed_biguint!(1_000),
+                max_active_boosts: 2,
+            });
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .set_egld_balance(&first_user, &rust_biguint!(2_000));
+    for _ in 0..2 {
+        setup
+            .b_mock
+            .execute_tx(
+                &first_user,
+                &setup.gas_burner_wrapper,
+                &rust_biguint!(1_000),
+                |sc| {
+                    sc.boost();
+                },
+            )
+            .assert_ok();
+    }
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_boost_multiplier(managed_address!(&first_user)),
+                BASE_MULTIPLIER + MAX_MULTIPLIER
+            );
+        })
+        .assert_ok();
+
+    // the boost EGLD is split with the dev rewards, half of it going to the guild prize pool
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(1_000));
+    setup.b_mock.set_block_epoch(7);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.claim_rewards(1), managed_biguint!(2_000));
+
+                assert!(sc.pending_boost_rewards().is_empty());
+                assert_eq!(sc.guild_rewards_week(1).get(), managed_biguint!(2_000));
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(2_000));
+
+                assert_eq!(sc.claim_guild_rewards(1), managed_biguint!(2_000));
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    // with no one working during the week, the boost EGLD is released once the week is pruned
+    setup
+        .b_mock
+        .set_egld_balance(&first_user, &rust_biguint!(1_000));
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.boost();
+            },
+        )
+        .assert_ok();
+    setup.b_mock.set_block_epoch(14);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.claim_rewards(2), managed_biguint!(0));
+
+                assert!(sc.pending_boost_rewards().is_empty());
+                assert_eq!(sc.total_rewards_week(2).get(), managed_biguint!(1_000));
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(1_000));
+
+                assert!(sc.prune_week(2, 10));
+                assert_eq!(sc.outstanding_rewards().get(), managed_biguint!(0));
+                assert_eq!(
+                    sc.get_recoverable_balance(EgldOrEsdtTokenIdentifier::egld(), 0),
+                    managed_biguint!(1_000)
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn scoring_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_scoring_curve(ScoringCurve::SquareRoot);
+        })
+        .assert_ok();
+
+    setup.work_user(&first_user);
+
+    // changing the curve mid-week does not affect the current week
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            let mut segments = ManagedVec::new();
+            segments.push(ScoringSegment {
+                from_gas: managed_biguint!(150_000_000),
+                rate: BASE_MULTIPLIER + 1,
+            });
+            sc.set_scoring_curve(ScoringCurve::Piecewise(segments));
+        })
+        .assert_user_error("Invalid rate");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            let mut segments = ManagedVec::new();
+            segments.push(ScoringSegment {
+                from_gas: managed_biguint!(150_000_000),
+                rate: BASE_MULTIPLIER / 2,
+            });
+            sc.set_scoring_curve(ScoringCurve::Piecewise(segments));
+        })
+        .assert_ok();
+
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.scoring_curve_for_week(1).get(), ScoringCurve::SquareRoot);
+            assert_eq!(
+                sc.leaderboard(1).get(1),
+                LeaderboardEntry {
+                    user_id: 1,
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(14_142),
+                }
+            );
+        })
+        .assert_ok();
+
+    // 150_000_000 gas in full, then 50_000_000 gas at half rate
+    setup.b_mock.set_block_epoch(8);
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.leaderboard(2).get(1),
+                LeaderboardEntry {
+                    user_id: 1,
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(175_000_000),
+                }
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn streak_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_streak_config(StreakConfig {
+                min_weekly_gas: managed_biguint!(150_000_000),
+                bonus_per_week: 1_000,
+                max_bonus: 1_500,
+            });
+        })
+        .assert_ok();
+
+    // the week only counts once the minimum gas is reached
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 0);
+        })
+        .assert_ok();
+
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 1);
+            assert_eq!(
+                sc.get_streak_multiplier(managed_address!(&first_user)),
+                BASE_MULTIPLIER
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_epoch(8);
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 2);
+            assert_eq!(
+                sc.get_streak_multiplier(managed_address!(&first_user)),
+                11_000
+            );
+            assert_eq!(
+                sc.leaderboard(2).get(1).score,
+                managed_biguint!(220_000_000)
+            );
+        })
+        .assert_ok();
+
+    // the bonus is capped
+    setup.b_mock.set_block_epoch(15);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 2);
+            assert_eq!(
+                sc.get_streak_multiplier(managed_address!(&first_user)),
+                11_500
+            );
+        })
+        .assert_ok();
+
+    // a missed week resets the streak
+    setup.b_mock.set_block_epoch(22);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 0);
+            assert_eq!(
+                sc.get_streak_multiplier(managed_address!(&first_user)),
+                BASE_MULTIPLIER
+            );
+        })
+        .assert_ok();
+
+    setup.work_user(&first_user);
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.get_streak(managed_address!(&first_user)), 1);
+            assert_eq!(
+                sc.leaderboard(4).get(1).score,
+                managed_biguint!(200_000_000)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn referral_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let third_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_referral_config(ReferralConfig {
+                reward_kind: ReferralRewardKind::Gas,
+                percentage: 1_000,
+                duration_weeks: 1,
+                max_referees: 1,
+            });
+
+            // ids are also created without working, e.g. by joining a guild
+            for user in [&first_user, &third_user] {
+                let _ = sc.user_id().get_id_or_insert(&managed_address!(user));
+            }
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_with_referrer(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Unknown referrer");
+
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_with_referrer(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    // 10% of the referee's minted GAS
+    setup
+        .b_mock
+        .check_esdt_balance(&first_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(110));
+    setup
+        .b_mock
+        .check_esdt_balance(&second_user, GAS_BURNER_TOKEN_ID, &rust_biguint!(100));
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_referrer(managed_address!(&second_user))
+                    .into_option(),
+                Some(managed_address!(&first_user))
+            );
+            assert_eq!(
+                sc.get_referees(managed_address!(&first_user)).to_vec(),
+                ManagedVec::from_single_item(managed_address!(&second_user))
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_with_referrer(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Referrer can only be set on the first work");
+    setup
+        .b_mock
+        .execute_tx(
+            &third_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.work_with_referrer(
+                    managed_address!(&first_user),
+                    managed_buffer!(b"signature"),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Referrer has too many referees");
+
+    // half of the referee's score increase, also credited to the referrer's guild
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_guild_config(GuildConfig {
+                max_members: 2,
+                switch_cooldown_epochs: 3,
+                prize_pool_percentage: 0,
+            });
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.create_guild(managed_buffer!(b"alpha")), 1);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_referral_config(ReferralConfig {
+                reward_kind: ReferralRewardKind::Score,
+                percentage: 5_000,
+                duration_weeks: 1,
+                max_referees: 1,
+            });
+        })
+        .assert_ok();
+
+    setup.work_user(&second_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.leaderboard(1).get(2),
+                LeaderboardEntry {
+                    user_id: 1,
+                    raw_gas: managed_biguint!(100_000_000),
+                    score: managed_biguint!(150_000_000),
+                }
+            );
+            assert_eq!(
+                sc.guild_leaderboard(1).get(1),
+                LeaderboardEntry {
+                    user_id: 1,
+                    raw_gas: managed_biguint!(0),
+                    score: managed_biguint!(50_000_000),
+                }
+            );
+            assert_eq!(
+                sc.guild_member_score(1, 1).get(),
+                managed_biguint!(50_000_000)
+            );
+        })
+        .assert_ok();
+
+    // the kickbacks end after the configured duration
+    setup.b_mock.set_block_epoch(8);
+    setup.work_user(&second_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.leaderboard(2).len(), 1);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.remove_referral(managed_address!(&second_user));
+
+            assert!(sc
+                .get_referrer(managed_address!(&second_user))
+                .into_option()
+                .is_none());
+            assert_eq!(sc.get_referees(managed_address!(&first_user)).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn guild_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let third_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+
+    // the guild and staking pools cannot take more than the whole dev rewards
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_unbond_epochs(1);
+            sc.set_staking_pool_percentage(6_000);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_guild_config(GuildConfig {
+                max_members: 2,
+                switch_cooldown_epochs: 3,
+                prize_pool_percentage: 5_000,
+            });
+        })
+        .assert_user_error("Invalid percentage");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_staking_pool_percentage(0);
+            sc.set_guild_config(GuildConfig {
+                max_members: 2,
+                switch_cooldown_epochs: 3,
+                prize_pool_percentage: 5_000,
+            });
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.create_guild(managed_buffer!(b"alpha")), 1);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.join_guild(1);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &third_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.join_guild(1);
+            },
+        )
+        .assert_user_error("Guild is full");
+    setup
+        .b_mock
+        .execute_tx(
+            &third_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.create_guild(managed_buffer!(b"beta")), 2);
+            },
+        )
+        .assert_ok();
+
+    setup.work_user(&first_user);
+    setup.work_user(&second_user);
+    setup.work_user(&third_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.guild_leaderboard(1).get(1),
+                LeaderboardEntry {
+                    user_id: 1,
+                    raw_gas: managed_biguint!(200_000_000),
+                    score: managed_biguint!(200_000_000),
+                }
+            );
+            assert_eq!(sc.guild_leaderboard(1).get(2).user_id, 2);
+            assert_eq!(sc.get_guild_members(1).len(), 2);
+        })
+        .assert_ok();
+
+    // the week's work still counts for the guild the user left
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.leave_guild();
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.join_guild(2);
+            },
+        )
+        .assert_user_error("Guild switch cooldown not over");
+
+    setup.work_user(&second_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.guild_leaderboard(1).get(1).score,
+                managed_biguint!(300_000_000)
+            );
+            assert_eq!(sc.get_user_guild(managed_address!(&second_user)), 0);
+        })
+        .assert_ok();
+
+    // half of the dev rewards go to the guild prize pool,
+    // split by contribution between the members of the top guilds
+    setup
+        .b_mock
+        .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+    setup.b_mock.set_block_epoch(7);
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let guild_rewards = sc.claim_guild_rewards(1);
+                assert_eq!(guild_rewards, managed_biguint!(25_000));
+                assert_eq!(sc.guild_rewards_week(1).get(), managed_biguint!(50_000));
+                assert_eq!(sc.total_rewards_week(1).get(), managed_biguint!(50_000));
+
+                sc.join_guild(2);
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &third_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert_eq!(sc.claim_guild_rewards(1), managed_biguint!(12_500));
+                assert_eq!(sc.claim_guild_rewards(1), managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&second_user, &rust_biguint!(25_000));
+
+    // the 3 user entries go first, then the 2 guild entries
+    setup.b_mock.set_block_epoch(7 * 14);
+    setup
+        .b_mock
+        .execute_tx(
+            &first_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(!sc.prune_week(1, 4));
+                assert_eq!(sc.guild_leaderboard(1).len(), 1);
+                assert!(sc.user_guild_for_week(2, 1).is_empty());
+                assert!(sc.guild_member_score(2, 1).is_empty());
+                assert!(!sc.user_claimed_guild_rewards_for_week(3, 1).get());
+
+                assert!(sc.prune_week(1, 4));
+                assert!(sc.guild_leaderboard(1).is_empty());
+                assert_eq!(sc.guild_index_in_leaderboard(1, 1).get(), 0);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn league_test() {
+    let mut setup = GasBurnerSetup::new(gas_burner::contract_obj, owner_sc::contract_obj);
+
+    let owner = setup.owner.clone();
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let third_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let gas_burner = setup.gas_burner_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_league_config(LeagueConfig {
+                nr_promoted: 1,
+                nr_relegated: 1,
+                bronze_pool_percentage: u32::MAX,
+                silver_pool_percentage: 10_001,
+                gold_pool_percentage: 0,
+            });
+        })
+        .assert_user_error("Pool percentages must add up to the maximum");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_league_config(LeagueConfig {
+                nr_promoted: 1,
+                nr_relegated: 1,
+                bronze_pool_percentage: 2_000,
+                silver_pool_percentage: 3_000,
+                gold_pool_percentage: 5_000,
+            });
+        })
+        .assert_ok();
+
+    // leagues start with the next week
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert!(sc.league_config_for_week(1).is_empty());
+            assert_eq!(sc.division_leaderboard(Division::Bronze, 1).len(), 0);
+        })
+        .assert_ok();
+
+    let weekly_work = [
+        (7, vec![(&first_user, 2), (&second_user, 1)]),
+        (
+            14,
+            vec![(&first_user, 1), (&second_user, 2), (&third_user, 1)],
+        ),
+        (
+            21,
+            vec![(&first_user, 1), (&second_user, 1), (&third_user, 1)],
+        ),
+        (28, vec![(&first_user, 1), (&second_user, 2)]),
+    ];
+    for (epoch, users_work) in weekly_work.iter() {
+        setup.b_mock.set_block_epoch(*epoch);
+        for (user, nr_works) in users_work.iter() {
+            for _ in 0..*nr_works {
+                setup.work_user(user);
+            }
+        }
+
+        // the week before is rewarded from each division's pool
+        if *epoch == 14 {
+            setup
+                .b_mock
+                .set_developer_rewards(&gas_burner, rust_biguint!(100_000));
+            setup
+                .b_mock
+                .execute_tx(
+                    &first_user,
+                    &setup.gas_burner_wrapper,
+                    &rust_biguint!(0),
+                    |sc| {
+                        assert_eq!(sc.claim_rewards(2), managed_biguint!(13_333));
+                    },
+                )
+                .assert_ok();
+        }
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(sc.division_leaderboard(Division::Bronze, 2).len(), 2);
+            assert_eq!(
+                sc.division_leaderboard(Division::Silver, 3).get(1).user_id,
+                1
+            );
+            assert_eq!(sc.division_leaderboard(Division::Gold, 4).get(1).user_id, 1);
+            assert_eq!(
+                sc.division_leaderboard(Division::Silver, 4).get(1).user_id,
+                2
+            );
+
+            assert_eq!(sc.division_leaderboard(Division::Gold, 5).len(), 2);
+            assert_eq!(sc.division_leaderboard(Division::Gold, 5).get(1).user_id, 2);
+        })
+        .assert_ok();
+
+    // the first work of a week finalizes the previous one,
+    // relegating the bottom of gold
+    setup.b_mock.set_block_epoch(35);
+    setup.work_user(&third_user);
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_user_division(managed_address!(&first_user)),
+                Division::Silver
+            );
+            assert_eq!(
+                sc.get_user_division(managed_address!(&second_user)),
+                Division::Gold
+            );
+            assert_eq!(
+                sc.get_user_division(managed_address!(&third_user)),
+                Division::Silver
+            );
+        })
+        .assert_ok();
+
+    // each pruned user's own division entry is removed
+    setup.b_mock.set_block_epoch(7 * 20);
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            while !sc.prune_week(3, 1) {
+                let mut nr_division_entries = 0;
+                for division in Division::ALL {
+                    let leaderboard_mapper = sc.division_leaderboard(division, 3);
+                    for i in 1..=leaderboard_mapper.len() {
+                        let entry = leaderboard_mapper.get(i);
+                        assert!(sc.user_index_in_leaderboard(entry.user_id, 3).get() != 0);
+                        assert_eq!(sc.division_index_in_leaderboard(entry.user_id, 3).get(), i);
+                        assert_eq!(sc.user_division_for_week(entry.user_id, 3).get(), division);
+                    }
+                    nr_division_entries += leaderboard_mapper.len();
+                }
+                assert_eq!(nr_division_entries, sc.leaderboard(3).len());
+            }
+
+            for division in Division::ALL {
+                assert!(sc.division_leaderboard(division, 3).is_empty());
+            }
+        })
+        .assert_ok();
+
+    // once cleared, the current week is the last one played in divisions
+    setup.work_user(&first_user);
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.clear_league_config();
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.gas_burner_wrapper, &rust_biguint!(0), |sc| {
+            sc.clear_league_config();
+        })
+        .assert_user_error("Leagues not enabled");
+
+    // anyone may finalize a week without working
+    setup.b_mock.set_block_epoch(7 * 21);
+    setup
+        .b_mock
+        .execute_tx(
+            &second_user,
+            &setup.gas_burner_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.finalize_previous_league_week();
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.gas_burner_wrapper, |sc| {
+            assert_eq!(
+                sc.get_user_division(managed_address!(&first_user)),
+                Division::Gold
+            );
+            assert!(!sc.league_config_for_week(21).is_empty());
+            assert!(sc.league_config_for_week(22).is_empty());
+        })
+        .assert_ok();
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
-// Async Callback (empty):               1
-// Total number of exported functions:  14
+// Endpoints:                          108
+// Async Callback:                       1
+// Total number of exported functions: 111
 
 #![no_std]
 
@@ -21,17 +21,114 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         work => work
-        getTokenId => token
+        workWithReferrer => work_with_referrer
+        workFor => work_for
         getUserLeaderboardPlacement => get_user_leaderboard_placement
         getLeaderboardForWeek => leaderboard
+        getGuildLeaderboardForWeek => guild_leaderboard
+        getDivisionLeaderboardForWeek => division_leaderboard
+        setLeagueConfig => set_league_config
+        clearLeagueConfig => clear_league_config
+        finalizeLeagueWeek => finalize_previous_league_week
+        getUserDivision => get_user_division
+        getLeagueConfig => league_config
+        getLeagueConfigForWeek => league_config_for_week
+        setGuildConfig => set_guild_config
+        createGuild => create_guild
+        joinGuild => join_guild
+        leaveGuild => leave_guild
+        getUserGuild => get_user_guild
+        getGuildMembers => get_guild_members
+        getGuildConfig => guild_config
+        getGuildInfo => guild_info
+        getGuildRewardsForWeek => guild_rewards_week
         claimRewards => claim_rewards
+        claimGuildRewards => claim_guild_rewards
+        setClaimWindow => set_claim_window
+        getPendingBoostRewards => pending_boost_rewards
+        getOutstandingRewards => outstanding_rewards
+        isLegacyClaimUnresolved => legacy_user_claimed_for_week
+        getClaimWindowWeeks => claim_window_weeks
+        getClaimWindowStartWeek => claim_window_start_week
+        setBoostConfig => set_boost_config
+        boost => boost
+        getActiveBoosts => get_active_boosts
+        getBoostMultiplier => get_boost_multiplier
+        getBoostConfig => boost_config
+        recoverFunds => recover_funds
+        getRecoverableBalance => get_recoverable_balance
+        setReferralConfig => set_referral_config
+        removeReferral => remove_referral
+        getReferrer => get_referrer
+        getReferees => get_referees
+        getReferralConfig => referral_config
+        pruneWeek => prune_week
+        getWeekSummary => week_summary
         getUserNonce => get_user_nonce
+        getUsedNoncesInWindow => get_used_nonces_in_window
+        addSigner => add_signer
+        removeSigner => remove_signer
+        rotateSigner => rotate_signer
+        setSignatureThreshold => set_signature_threshold
+        setChainId => set_chain_id
+        getSigners => get_signers
+        getChainId => chain_id
+        getSignatureThreshold => signature_threshold
+        setWorkVerificationMode => set_work_verification_mode
+        setPowConfig => set_pow_config
+        getWeekSeed => get_week_seed
+        getPowDifficulty => get_pow_difficulty
+        getWorkVerificationMode => work_verification_mode
+        getPowConfig => pow_config
+        getSubmissionsForWeek => submissions_for_week
+        registerSessionKey => register_session_key
+        revokeSessionKey => revoke_session_key
+        getSessionKey => get_session_key
         getCurrentWeek => get_current_week
         getFirstWeekStartEpoch => first_week_start_epoch
+        pauseFlag => pause_flag
+        unpauseFlag => unpause_flag
+        getPauseStatus => get_pause_status
+        getStorageVersion => storage_version
+        setStreakConfig => set_streak_config
+        getStreak => get_streak
+        getStreakMultiplier => get_streak_multiplier
+        getStreakConfig => streak_config
+        setMultiplierTiers => set_multiplier_tiers
+        setUnbondEpochs => set_unbond_epochs
+        stake => stake
+        unstake => unstake
+        unbond => unbond
+        getWorkMultiplier => get_work_multiplier
+        getMultiplierTiers => multiplier_tiers
+        getUnbondingStake => unbonding_stake
+        getTotalStaked => total_staked
+        setScoringCurve => set_scoring_curve
+        getScoringCurve => scoring_curve
+        getScoringCurveForWeek => scoring_curve_for_week
+        setStakingPoolPercentage => set_staking_pool_percentage
+        claimStakingRewards => claim_staking_rewards
+        getClaimableStakingRewards => get_claimable_staking_rewards
+        getStakingPoolPercentage => staking_pool_percentage
+        getStakedAmount => staked_amount
+        getTotalActiveStake => total_active_stake
+        getPendingStake => pending_stake
+        getUserPendingStake => user_pending_stake
+        getUnbondEpochs => unbond_epochs
+        getStakingRewardsOutstanding => staking_rewards_outstanding
+        issueToken => issue_token
+        issueLockedToken => issue_locked_token
+        getTokenId => token
+        getLockedTokenId => locked_token
+        setMintMode => set_mint_mode
+        unlock => unlock
+        getMintMode => mint_mode
+        continueMigration => continue_migration
+        resolveLegacyClaim => resolve_legacy_claim
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { gas_burner }
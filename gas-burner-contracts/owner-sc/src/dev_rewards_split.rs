@@ -0,0 +1,94 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type DevRewardsClaimId = u64;
+
+pub const MAX_PERCENTAGE: u32 = 10_000;
+
+/// Percentages are out of MAX_PERCENTAGE, the prize pool gets whatever is left
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct DevRewardsSplit<M: ManagedTypeApi> {
+    pub treasury: ManagedAddress<M>,
+    pub treasury_percentage: u32,
+    pub buyback: ManagedAddress<M>,
+    pub buyback_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct DevRewardsClaim<M: ManagedTypeApi> {
+    pub gas_burner: ManagedAddress<M>,
+    pub prize_pool_amount: BigUint<M>,
+    pub treasury_amount: BigUint<M>,
+    pub buyback_amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait DevRewardsSplitModule {
+    fn set_dev_rewards_split(&self, split: DevRewardsSplit<Self::Api>) {
+        // checked one by one first, so the sum cannot overflow
+        require!(
+            split.treasury_percentage <= MAX_PERCENTAGE
+                && split.buyback_percentage <= MAX_PERCENTAGE
+                && split.treasury_percentage + split.buyback_percentage <= MAX_PERCENTAGE,
+            "Invalid percentages"
+        );
+        require!(
+            split.treasury_percentage == 0 || !split.treasury.is_zero(),
+            "Invalid treasury address"
+        );
+        require!(
+            split.buyback_percentage == 0 || !split.buyback.is_zero(),
+            "Invalid buyback address"
+        );
+
+        self.dev_rewards_split().set(split);
+    }
+
+    /// Sends the treasury and buyback shares and returns the prize pool share
+    fn split_dev_rewards(&self, gas_burner: ManagedAddress, dev_rewards: BigUint) -> BigUint {
+        let split_mapper = self.dev_rewards_split();
+        let mut treasury_amount = BigUint::zero();
+        let mut buyback_amount = BigUint::zero();
+        if !split_mapper.is_empty() {
+            let split = split_mapper.get();
+            treasury_amount = &dev_rewards * split.treasury_percentage / MAX_PERCENTAGE;
+            buyback_amount = &dev_rewards * split.buyback_percentage / MAX_PERCENTAGE;
+
+            self.send()
+                .direct_non_zero_egld(&split.treasury, &treasury_amount);
+            self.send()
+                .direct_non_zero_egld(&split.buyback, &buyback_amount);
+        }
+
+        let prize_pool_amount = dev_rewards - &treasury_amount - &buyback_amount;
+        let claim_id = self.last_dev_rewards_claim_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        self.dev_rewards_claim(claim_id).set(DevRewardsClaim {
+            gas_burner,
+            prize_pool_amount: prize_pool_amount.clone(),
+            treasury_amount,
+            buyback_amount,
+        });
+
+        prize_pool_amount
+    }
+
+    #[view(getDevRewardsSplit)]
+    #[storage_mapper("devRewardsSplit")]
+    fn dev_rewards_split(&self) -> SingleValueMapper<DevRewardsSplit<Self::Api>>;
+
+    #[view(getDevRewardsClaim)]
+    #[storage_mapper("devRewardsClaim")]
+    fn dev_rewards_claim(
+        &self,
+        claim_id: DevRewardsClaimId,
+    ) -> SingleValueMapper<DevRewardsClaim<Self::Api>>;
+
+    #[view(getLastDevRewardsClaimId)]
+    #[storage_mapper("lastDevRewardsClaimId")]
+    fn last_dev_rewards_claim_id(&self) -> SingleValueMapper<DevRewardsClaimId>;
+}
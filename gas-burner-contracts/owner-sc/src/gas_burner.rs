@@ -0,0 +1,269 @@
+use crate::{
+    gas_burner_proxy::{GasBurnerProxy, MintMode, PauseFlag, WorkVerificationMode},
+    roles::AdminRole,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Endpoints with a dedicated action, which may need another role than the configurator,
+/// and built-in functions acting on the gas burner's account
+pub const RESTRICTED_GAS_BURNER_ENDPOINTS: &[&[u8]] = &[
+    b"pause",
+    b"unpause",
+    b"pauseFlag",
+    b"unpauseFlag",
+    b"addSigner",
+    b"removeSigner",
+    b"rotateSigner",
+    b"setSignatureThreshold",
+    b"setChainId",
+    b"setWorkVerificationMode",
+    b"setPowConfig",
+    b"setMintMode",
+    b"issueToken",
+    b"issueLockedToken",
+    b"recoverFunds",
+    b"ChangeOwnerAddress",
+    b"ClaimDeveloperRewards",
+    b"upgradeContract",
+];
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum UpgradeSource<M: ManagedTypeApi> {
+    SourceAddress(ManagedAddress<M>),
+    Code(ManagedBuffer<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct GasBurnerUpgrade<M: ManagedTypeApi> {
+    pub gas_burner: ManagedAddress<M>,
+    pub source: UpgradeSource<M>,
+    pub code_metadata: CodeMetadata,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum GasBurnerTokenKind {
+    Liquid,
+    Locked,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct GasBurnerTokenIssue<M: ManagedTypeApi> {
+    pub gas_burner: ManagedAddress<M>,
+    pub token_kind: GasBurnerTokenKind,
+    pub token_display_name: ManagedBuffer<M>,
+    pub token_ticker: ManagedBuffer<M>,
+    pub num_decimals: usize,
+    pub issue_cost: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait GasBurnerModule: crate::roles::RolesModule + crate::reserve::ReserveModule {
+    /// Emergency pause, instant for any pauser
+    /// Unpausing goes through a proposal
+    #[endpoint(pauseGasBurner)]
+    fn pause_gas_burner(&self, gas_burner: ManagedAddress) {
+        self.require_role(AdminRole::Pauser);
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .pause_endpoint()
+            .sync_call();
+    }
+
+    #[endpoint(pauseAllGasBurners)]
+    fn pause_all_gas_burners(&self) {
+        self.require_role(AdminRole::Pauser);
+
+        for gas_burner in self.gas_burners().iter() {
+            self.tx()
+                .to(&gas_burner)
+                .typed(GasBurnerProxy)
+                .pause_endpoint()
+                .sync_call();
+        }
+    }
+
+    /// Pauses a single feature, instant for any pauser
+    #[endpoint(pauseGasBurnerFlag)]
+    fn pause_gas_burner_flag(&self, gas_burner: ManagedAddress, flag: PauseFlag) {
+        self.require_role(AdminRole::Pauser);
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .pause_flag(flag)
+            .sync_call();
+    }
+
+    fn set_gas_burner_work_verification_mode(
+        &self,
+        gas_burner: ManagedAddress,
+        mode: WorkVerificationMode,
+    ) {
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .set_work_verification_mode(mode)
+            .sync_call();
+    }
+
+    fn set_gas_burner_pow_config(
+        &self,
+        gas_burner: ManagedAddress,
+        initial_difficulty: u32,
+        target_submissions_per_week: u64,
+    ) {
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .set_pow_config(initial_difficulty, target_submissions_per_week)
+            .sync_call();
+    }
+
+    fn set_gas_burner_mint_mode(&self, gas_burner: ManagedAddress, mode: MintMode) {
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .set_mint_mode(mode)
+            .sync_call();
+    }
+
+    /// The upgrade is an async call, so nothing may be done after it
+    /// It goes through the legacy async call mechanism, so its callback is saved the same way
+    fn upgrade_gas_burner(
+        &self,
+        upgrade: GasBurnerUpgrade<Self::Api>,
+        callback: CallbackClosure<Self::Api>,
+    ) {
+        self.require_registered_gas_burner(&upgrade.gas_burner);
+        callback.save_to_storage::<Self::Api>();
+
+        let upgrade_tx = self
+            .tx()
+            .to(upgrade.gas_burner)
+            .typed(GasBurnerProxy)
+            .upgrade(OptionalValue::<ManagedBuffer>::None)
+            .code_metadata(upgrade.code_metadata)
+            .arguments_raw(upgrade.arguments.into());
+        match upgrade.source {
+            UpgradeSource::SourceAddress(source_address) => upgrade_tx
+                .from_source(source_address)
+                .upgrade_async_call_and_exit(),
+            UpgradeSource::Code(code) => upgrade_tx.code(code).upgrade_async_call_and_exit(),
+        }
+    }
+
+    /// The issue cost is paid from the reserve
+    /// The issue is an async call, so nothing may be done after it
+    /// If the gas burner rejects the call, the cost goes back to the reserve
+    /// If the issue itself fails, the gas burner refunds it to this SC outside the reserve
+    fn issue_gas_burner_token(
+        &self,
+        issue: GasBurnerTokenIssue<Self::Api>,
+        callback: CallbackClosure<Self::Api>,
+    ) {
+        self.require_registered_gas_burner(&issue.gas_burner);
+        self.reserve().update(|reserve| {
+            require!(
+                *reserve >= issue.issue_cost,
+                "Not enough EGLD in the reserve"
+            );
+            *reserve -= &issue.issue_cost;
+        });
+
+        let gas_burner_tx = self.tx().to(&issue.gas_burner).typed(GasBurnerProxy);
+        match issue.token_kind {
+            GasBurnerTokenKind::Liquid => gas_burner_tx
+                .issue_token(
+                    issue.token_display_name,
+                    issue.token_ticker,
+                    issue.num_decimals,
+                )
+                .egld(&issue.issue_cost)
+                .callback(callback)
+                .async_call_and_exit(),
+            GasBurnerTokenKind::Locked => gas_burner_tx
+                .issue_locked_token(
+                    issue.token_display_name,
+                    issue.token_ticker,
+                    issue.num_decimals,
+                )
+                .egld(&issue.issue_cost)
+                .callback(callback)
+                .async_call_and_exit(),
+        }
+    }
+
+    /// The gas burner is removed from the registry, as it is no longer managed by this SC
+    fn transfer_gas_burner_ownership(&self, gas_burner: ManagedAddress, new_owner: ManagedAddress) {
+        self.require_registered_gas_burner(&gas_burner);
+        require!(!new_owner.is_zero(), "Invalid new owner");
+
+        let _ = self.gas_burners().swap_remove(&gas_burner);
+        self.tx()
+            .to(&gas_burner)
+            .typed(system_proxy::UserBuiltinProxy)
+            .change_owner_address(&new_owner)
+            .sync_call();
+    }
+
+    /// For admin endpoints without a dedicated action
+    fn call_gas_burner(
+        &self,
+        gas_burner: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        arguments: ManagedVec<ManagedBuffer>,
+    ) {
+        self.require_registered_gas_burner(&gas_burner);
+        require!(
+            !RESTRICTED_GAS_BURNER_ENDPOINTS
+                .iter()
+                .any(|restricted_name| endpoint_name == **restricted_name),
+            "Endpoint has a dedicated action"
+        );
+
+        self.tx()
+            .to(&gas_burner)
+            .raw_call(endpoint_name)
+            .arguments_raw(arguments.into())
+            .sync_call();
+    }
+
+    fn require_valid_gas_burner_address(&self, gas_burner: &ManagedAddress) {
+        require!(
+            !gas_burner.is_zero() && self.blockchain().is_smart_contract(gas_burner),
+            "Invalid SC address"
+        );
+    }
+
+    fn require_registered_gas_burner(&self, gas_burner: &ManagedAddress) {
+        require!(
+            self.gas_burners().contains(gas_burner),
+            "Unknown gas burner"
+        );
+    }
+
+    #[view(getGasBurners)]
+    #[storage_mapper("gasBurners")]
+    fn gas_burners(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Single gas burner address used by previous versions, moved to the registry on upgrade
+    #[storage_mapper("gasBurner")]
+    fn legacy_gas_burner(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -0,0 +1,1734 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct GasBurnerProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for GasBurnerProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = GasBurnerProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        GasBurnerProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct GasBurnerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> GasBurnerProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// The token may be issued beforehand, with the Mint role granted externally, 
+    /// or later through issueToken 
+    /// The chain id is the one signers include in their messages 
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<TokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        owner_sc: Arg0,
+        signer: Arg1,
+        chain_id: Arg2,
+        opt_token_id: Arg3,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&owner_sc)
+            .argument(&signer)
+            .argument(&chain_id)
+            .argument(&opt_token_id)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GasBurnerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Work and claims are disabled until the migration completes, 
+    /// use continueMigration if it does not fit in the upgrade transaction 
+    /// Weeks from before the claim window was introduced count their window from the upgrade 
+    /// The chain id must be given if not set yet, so signed work keeps going through 
+    pub fn upgrade<
+        Arg0: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        opt_chain_id: Arg0,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .argument(&opt_chain_id)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GasBurnerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// In signature mode, the proof is an authorized signer's WorkSignature for the user and nonce 
+    /// In proof of work mode, the proof is a solution for the current week's puzzle 
+    /// Nonce starts from 0 and you can get it through the getUserNonce view 
+    /// Any unused nonce within the window may be given, so several transactions can be in flight 
+    pub fn work<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        proof: Arg0,
+        opt_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("work")
+            .argument(&proof)
+            .argument(&opt_nonce)
+            .original_result()
+    }
+
+    /// Same as work, for a user's first work only, crediting the referrer with kickbacks 
+    /// on the user's work, see the referral module 
+    pub fn work_with_referrer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        referrer: Arg0,
+        proof: Arg1,
+        opt_nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("workWithReferrer")
+            .argument(&referrer)
+            .argument(&proof)
+            .argument(&opt_nonce)
+            .original_result()
+    }
+
+    /// Called by a user's delegate, registered through registerSessionKey 
+    /// Rewards and leaderboard progress go to the user 
+    /// The gas used counts against the session key's allowance 
+    /// The proof is built for the user, the same as for work 
+    pub fn work_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        user: Arg0,
+        proof: Arg1,
+        opt_nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("workFor")
+            .argument(&user)
+            .argument(&proof)
+            .argument(&opt_nonce)
+            .original_result()
+    }
+
+    /// A placement of 0 means the user does not exist for the given week 
+    pub fn get_user_leaderboard_placement<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        user: Arg0,
+        week: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserLeaderboardPlacement")
+            .argument(&user)
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn leaderboard<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLeaderboardForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn guild_leaderboard<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuildLeaderboardForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn division_leaderboard<
+        Arg0: ProxyArg<Division>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        division: Arg0,
+        week: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDivisionLeaderboardForWeek")
+            .argument(&division)
+            .argument(&week)
+            .original_result()
+    }
+
+    /// Applies from the next week 
+    pub fn set_league_config<
+        Arg0: ProxyArg<LeagueConfig>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLeagueConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// Leagues stop from the next week, the current one is still played in divisions 
+    pub fn clear_league_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearLeagueConfig")
+            .original_result()
+    }
+
+    /// Finalizes the previous league week without waiting for a work 
+    pub fn finalize_previous_league_week(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalizeLeagueWeek")
+            .original_result()
+    }
+
+    pub fn get_user_division<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Division> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDivision")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn league_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LeagueConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLeagueConfig")
+            .original_result()
+    }
+
+    /// Empty for weeks played without leagues 
+    pub fn league_config_for_week<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LeagueConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLeagueConfigForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    /// A lower member cap does not remove members from existing guilds 
+    /// The prize pool and the staking pool may not take more than the whole dev rewards 
+    pub fn set_guild_config<
+        Arg0: ProxyArg<GuildConfig>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGuildConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// The caller becomes the guild's first member 
+    pub fn create_guild<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createGuild")
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn join_guild<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        guild_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("joinGuild")
+            .argument(&guild_id)
+            .original_result()
+    }
+
+    /// The user may only join another guild once the switch cooldown passes 
+    pub fn leave_guild(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("leaveGuild")
+            .original_result()
+    }
+
+    /// A guild id of 0 means the user is not in a guild 
+    pub fn get_user_guild<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserGuild")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_guild_members<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        guild_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuildMembers")
+            .argument(&guild_id)
+            .original_result()
+    }
+
+    pub fn guild_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, GuildConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuildConfig")
+            .original_result()
+    }
+
+    pub fn guild_info<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        guild_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, GuildInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuildInfo")
+            .argument(&guild_id)
+            .original_result()
+    }
+
+    /// Guild prize pool of the week 
+    pub fn guild_rewards_week<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGuildRewardsForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    /// Weeks past the claim window are skipped 
+    /// While dev rewards collection is paused, the previous week is only claimable 
+    /// if its rewards were already collected 
+    pub fn claim_rewards<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        start_week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewards")
+            .argument(&start_week)
+            .original_result()
+    }
+
+    /// Members of the top guilds of a week split its guild prize pool, 
+    /// according to their contribution to their guild's score 
+    /// The same claim window and dev rewards collection rules as claimRewards apply 
+    pub fn claim_guild_rewards<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        start_week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimGuildRewards")
+            .argument(&start_week)
+            .original_result()
+    }
+
+    /// A shorter window only counts from the current week, 
+    /// so that no week becomes unclaimable at once 
+    pub fn set_claim_window<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        claim_window_weeks: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClaimWindow")
+            .argument(&claim_window_weeks)
+            .original_result()
+    }
+
+    /// EGLD paid for boosts, not split with the dev rewards yet 
+    pub fn pending_boost_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingBoostRewards")
+            .original_result()
+    }
+
+    /// EGLD rewards owed to users, over all weeks, including the guild prize pools 
+    pub fn outstanding_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutstandingRewards")
+            .original_result()
+    }
+
+    /// Claimed flags of storage version 1, kept per week only 
+    /// Set for weeks still waiting for resolveLegacyClaim 
+    pub fn legacy_user_claimed_for_week<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isLegacyClaimUnresolved")
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn claim_window_weeks(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimWindowWeeks")
+            .original_result()
+    }
+
+    pub fn claim_window_start_week(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimWindowStartWeek")
+            .original_result()
+    }
+
+    /// Changes only apply to boosts bought afterwards 
+    pub fn set_boost_config<
+        Arg0: ProxyArg<BoostConfig<Env::Api>>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBoostConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// Pay the exact price, either in GAS, which is burned, 
+    /// or in EGLD, which is split with the next collected dev rewards 
+    /// Active boosts stack, up to the configured maximum per user 
+    pub fn boost(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("boost")
+            .original_result()
+    }
+
+    pub fn get_active_boosts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ActiveBoost>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveBoosts")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Stacked bonuses are capped at MAX_MULTIPLIER 
+    pub fn get_boost_multiplier<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoostMultiplier")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn boost_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BoostConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoostConfig")
+            .original_result()
+    }
+
+    /// Withdraws funds sent by mistake or left over from rounding, 
+    /// never more than what is not owed to users 
+    /// EGLD is only recoverable once the storage is migrated, 
+    /// as the rewards owed for older weeks are only tracked from then on 
+    pub fn recover_funds<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+        amount: Arg2,
+        destination: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recoverFunds")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .argument(&amount)
+            .argument(&destination)
+            .original_result()
+    }
+
+    /// Only EGLD rewards, including the staking pool's and the boost payments not split yet, 
+    /// and staked GAS are owed to users, 
+    /// any other ESDT held by the SC is recoverable 
+    pub fn get_recoverable_balance<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecoverableBalance")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .original_result()
+    }
+
+    /// Changes apply to kickbacks paid afterwards, 
+    /// except for the duration, which is fixed when the referral is registered 
+    pub fn set_referral_config<
+        Arg0: ProxyArg<ReferralConfig>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReferralConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// Stops the kickbacks of an abusive referral 
+    pub fn remove_referral<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeReferral")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_referrer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferrer")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_referees<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferees")
+            .argument(&referrer)
+            .original_result()
+    }
+
+    pub fn referral_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReferralConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralConfig")
+            .original_result()
+    }
+
+    /// Anyone may prune a week that is fully claimed or past the claim window 
+    /// At most max_entries leaderboard entries are removed per call, 
+    /// the guild leaderboard's entries being removed after the users' 
+    /// Returns true once the week is fully pruned 
+    pub fn prune_week<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+        max_entries: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pruneWeek")
+            .argument(&week)
+            .argument(&max_entries)
+            .original_result()
+    }
+
+    pub fn week_summary<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WeekSummary<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWeekSummary")
+            .argument(&week)
+            .original_result()
+    }
+
+    /// Returns the lowest unused nonce, which is also the start of the user's nonce window 
+    pub fn get_user_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserNonce")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Nonces in the user's window that were already used, out of order 
+    pub fn get_used_nonces_in_window<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUsedNoncesInWindow")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn add_signer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        signer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addSigner")
+            .argument(&signer)
+            .original_result()
+    }
+
+    pub fn remove_signer<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        key_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeSigner")
+            .argument(&key_id)
+            .original_result()
+    }
+
+    /// Replaces the key behind an existing key id, 
+    /// so signatures from the previous key are no longer accepted 
+    pub fn rotate_signer<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        key_id: Arg0,
+        new_signer: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rotateSigner")
+            .argument(&key_id)
+            .argument(&new_signer)
+            .original_result()
+    }
+
+    /// A threshold of 0 disables M-of-N attestation for high-value actions 
+    pub fn set_signature_threshold<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSignatureThreshold")
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Signed messages include the chain id, so signatures can't be replayed on another chain 
+    pub fn set_chain_id<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        chain_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setChainId")
+            .argument(&chain_id)
+            .original_result()
+    }
+
+    pub fn get_signers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSigners")
+            .original_result()
+    }
+
+    pub fn chain_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getChainId")
+            .original_result()
+    }
+
+    pub fn signature_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSignatureThreshold")
+            .original_result()
+    }
+
+    pub fn set_work_verification_mode<
+        Arg0: ProxyArg<WorkVerificationMode>,
+    >(
+        self,
+        mode: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWorkVerificationMode")
+            .argument(&mode)
+            .original_result()
+    }
+
+    /// Difficulty is the number of leading zero bits required in the solution hash 
+    pub fn set_pow_config<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        initial_difficulty: Arg0,
+        target_submissions_per_week: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPowConfig")
+            .argument(&initial_difficulty)
+            .argument(&target_submissions_per_week)
+            .original_result()
+    }
+
+    /// The seed depends on the previous week's submission count, 
+    /// so solutions can't be precomputed before the week starts 
+    pub fn get_week_seed<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWeekSeed")
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn get_pow_difficulty<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPowDifficulty")
+            .argument(&week)
+            .original_result()
+    }
+
+    pub fn work_verification_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WorkVerificationMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkVerificationMode")
+            .original_result()
+    }
+
+    pub fn pow_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PowConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPowConfig")
+            .original_result()
+    }
+
+    pub fn submissions_for_week<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSubmissionsForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    /// The delegate may call workFor on behalf of the caller until the expiry epoch, 
+    /// using at most max_gas in total. Registering again replaces the previous session key 
+    /// In threshold mode, signers must attest the registration, see the signed_message module 
+    pub fn register_session_key<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, SignerSignature<Env::Api>>>,
+    >(
+        self,
+        delegate: Arg0,
+        expiry_epoch: Arg1,
+        max_gas: Arg2,
+        attestations: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerSessionKey")
+            .argument(&delegate)
+            .argument(&expiry_epoch)
+            .argument(&max_gas)
+            .argument(&attestations)
+            .original_result()
+    }
+
+    pub fn revoke_session_key(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeSessionKey")
+            .original_result()
+    }
+
+    pub fn get_session_key<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<SessionKey<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSessionKey")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Week starts from 1 
+    pub fn get_current_week(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentWeek")
+            .original_result()
+    }
+
+    pub fn first_week_start_epoch(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstWeekStartEpoch")
+            .original_result()
+    }
+
+    pub fn pause_flag<
+        Arg0: ProxyArg<PauseFlag>,
+    >(
+        self,
+        flag: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseFlag")
+            .argument(&flag)
+            .original_result()
+    }
+
+    pub fn unpause_flag<
+        Arg0: ProxyArg<PauseFlag>,
+    >(
+        self,
+        flag: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpauseFlag")
+            .argument(&flag)
+            .original_result()
+    }
+
+    pub fn get_pause_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseStatus")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+
+    /// Streaks are tracked even while no config is set, with any work counting 
+    pub fn set_streak_config<
+        Arg0: ProxyArg<StreakConfig<Env::Api>>,
+    >(
+        self,
+        config: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStreakConfig")
+            .argument(&config)
+            .original_result()
+    }
+
+    /// Consecutive qualifying weeks, up to the current one 
+    /// A streak is still ongoing if the user has not qualified yet for the current week 
+    pub fn get_streak<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStreak")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Multiplier applied to the user's work for the current week 
+    pub fn get_streak_multiplier<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStreakMultiplier")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn streak_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StreakConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStreakConfig")
+            .original_result()
+    }
+
+    /// Tiers must be sorted by stake, with increasing multipliers 
+    /// The highest tier reached by the user's stake applies to their work 
+    pub fn set_multiplier_tiers<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<BigUint<Env::Api>, u64>>>,
+    >(
+        self,
+        tiers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMultiplierTiers")
+            .argument(&tiers)
+            .original_result()
+    }
+
+    pub fn set_unbond_epochs<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        unbond_epochs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUnbondEpochs")
+            .argument(&unbond_epochs)
+            .original_result()
+    }
+
+    pub fn stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake")
+            .original_result()
+    }
+
+    /// The multiplier drops right away, the tokens can be withdrawn with unbond 
+    /// once the unbond period passes 
+    /// Unstaking again restarts the unbond period for the whole unbonding amount 
+    pub fn unstake<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unstake")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn unbond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unbond")
+            .original_result()
+    }
+
+    /// Only stake held since the start of the week counts, 
+    /// so staking right before working and unstaking after earns nothing 
+    pub fn get_work_multiplier<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkMultiplier")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn multiplier_tiers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, MultiplierTier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMultiplierTiers")
+            .original_result()
+    }
+
+    pub fn unbonding_stake<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UnbondingStake<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondingStake")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Staked and unbonding tokens, owed to the users 
+    pub fn total_staked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalStaked")
+            .original_result()
+    }
+
+    /// Applies from the next week the curve is snapshotted for, 
+    /// which is the current week if no work was done in it yet 
+    pub fn set_scoring_curve<
+        Arg0: ProxyArg<ScoringCurve<Env::Api>>,
+    >(
+        self,
+        curve: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setScoringCurve")
+            .argument(&curve)
+            .original_result()
+    }
+
+    pub fn scoring_curve(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ScoringCurve<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getScoringCurve")
+            .original_result()
+    }
+
+    /// Curve applied to the week, set by the week's first work 
+    pub fn scoring_curve_for_week<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        week: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ScoringCurve<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getScoringCurveForWeek")
+            .argument(&week)
+            .original_result()
+    }
+
+    /// Percentage of the weekly dev rewards, out of MAX_PERCENTAGE, that goes to stakers 
+    /// The pool can only be enabled with a non-zero unbond period 
+    pub fn set_staking_pool_percentage<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStakingPoolPercentage")
+            .argument(&percentage)
+            .original_result()
+    }
+
+    pub fn claim_staking_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimStakingRewards")
+            .original_result()
+    }
+
+    pub fn get_claimable_staking_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableStakingRewards")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn staking_pool_percentage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingPoolPercentage")
+            .original_result()
+    }
+
+    pub fn staked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedAmount")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Staked tokens earning rewards, without the pending and unbonding ones 
+    pub fn total_active_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalActiveStake")
+            .original_result()
+    }
+
+    /// Stake of the current and previous weeks, not active yet 
+    pub fn pending_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, PendingStake<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingStake")
+            .original_result()
+    }
+
+    pub fn user_pending_stake<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, PendingStake<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserPendingStake")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Must be non-zero while the staking pool is enabled 
+    pub fn unbond_epochs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondEpochs")
+            .original_result()
+    }
+
+    /// EGLD owed to stakers, kept apart from the leaderboard rewards 
+    pub fn staking_rewards_outstanding(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingRewardsOutstanding")
+            .original_result()
+    }
+
+    /// Issues the GAS token with every local role, including Mint, for this SC 
+    /// The issue cost is paid in EGLD and refunded to the owner if the issue fails 
+    pub fn issue_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+        num_decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .argument(&num_decimals)
+            .original_result()
+    }
+
+    /// Issues the meta-ESDT used for locked rewards, with every local role for this SC 
+    /// The issue cost is paid in EGLD and refunded to the owner if the issue fails 
+    pub fn issue_locked_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+        num_decimals: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueLockedToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .argument(&num_decimals)
+            .original_result()
+    }
+
+    pub fn token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenId")
+            .original_result()
+    }
+
+    pub fn locked_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedTokenId")
+            .original_result()
+    }
+
+    /// In locked mode, rewards minted by work are locked according to the schedule 
+    pub fn set_mint_mode<
+        Arg0: ProxyArg<MintMode>,
+    >(
+        self,
+        mode: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintMode")
+            .argument(&mode)
+            .original_result()
+    }
+
+    /// Burns the locked tokens and sends the liquid amount vested so far 
+    /// The still locked remainder is sent back as new locked tokens, 
+    /// vesting from the current epoch 
+    pub fn unlock(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("unlock")
+            .original_result()
+    }
+
+    pub fn mint_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MintMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintMode")
+            .original_result()
+    }
+
+    /// Anyone may push an unfinished migration forward 
+    /// Returns true once the storage is fully migrated 
+    pub fn continue_migration<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_weeks: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("continueMigration")
+            .argument(&max_weeks)
+            .original_result()
+    }
+
+    /// Settles a week claimed for under storage version 1, given the user who claimed, 
+    /// as found in the week's claim transactions 
+    /// Until then, the week's other top users cannot claim, nor lose their rewards 
+    pub fn resolve_legacy_claim<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        week: Arg0,
+        user: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveLegacyClaim")
+            .argument(&week)
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn pause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug)]
+pub struct LeaderboardEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user_id: u64,
+    pub raw_gas: BigUint<Api>,
+    pub score: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Division {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct LeagueConfig {
+    pub nr_promoted: usize,
+    pub nr_relegated: usize,
+    pub bronze_pool_percentage: u32,
+    pub silver_pool_percentage: u32,
+    pub gold_pool_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GuildConfig {
+    pub max_members: usize,
+    pub switch_cooldown_epochs: u64,
+    pub prize_pool_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct GuildInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name: ManagedBuffer<Api>,
+    pub founder: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct BoostConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub multiplier_bonus: u64,
+    pub duration_epochs: u64,
+    pub gas_price: BigUint<Api>,
+    pub egld_price: BigUint<Api>,
+    pub max_active_boosts: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug)]
+pub struct ActiveBoost {
+    pub multiplier_bonus: u64,
+    pub end_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct ReferralConfig {
+    pub reward_kind: ReferralRewardKind,
+    pub percentage: u32,
+    pub duration_weeks: usize,
+    pub max_referees: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum ReferralRewardKind {
+    Gas,
+    Score,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct WeekSummary<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub winners: ManagedVec<Api, LeaderboardEntry<Api>>,
+    pub nr_participants: usize,
+    pub total_top_work: BigUint<Api>,
+    pub total_rewards: BigUint<Api>,
+    pub rewards_paid: BigUint<Api>,
+    pub leaderboard_hash: ManagedByteArray<Api, 32usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum WorkVerificationMode {
+    Signature,
+    ProofOfWork,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PowConfig {
+    pub initial_difficulty: u32,
+    pub target_submissions_per_week: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Debug)]
+pub struct SignerSignature<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub key_id: u32,
+    pub signature: ManagedByteArray<Api, 64usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct SessionKey<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub delegate: ManagedAddress<Api>,
+    pub expiry_epoch: u64,
+    pub remaining_gas: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum PauseFlag {
+    Work,
+    Claims,
+    DevRewardsCollection,
+    Registration,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct PauseStatus {
+    pub global: bool,
+    pub work: bool,
+    pub claims: bool,
+    pub dev_rewards_collection: bool,
+    pub registration: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct StreakConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_weekly_gas: BigUint<Api>,
+    pub bonus_per_week: u64,
+    pub max_bonus: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct MultiplierTier<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_stake: BigUint<Api>,
+    pub multiplier: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct UnbondingStake<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub unbond_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub enum ScoringCurve<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Linear,
+    SquareRoot,
+    Piecewise(ManagedVec<Api, ScoringSegment<Api>>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Debug)]
+pub struct ScoringSegment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub from_gas: BigUint<Api>,
+    pub rate: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Debug)]
+pub struct PendingStake<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub week: usize,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum MintMode {
+    Liquid,
+    Locked(LockSchedule),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub struct LockSchedule {
+    pub lock_epochs: u64,
+    pub vesting_epochs: u64,
+}
@@ -0,0 +1,468 @@
+use crate::{
+    dev_rewards_split::DevRewardsSplit,
+    gas_burner::{GasBurnerTokenIssue, GasBurnerUpgrade},
+    gas_burner_proxy::{GasBurnerProxy, MintMode, PauseFlag, WorkVerificationMode},
+    roles::AdminRole,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type ProposalId = usize;
+pub type Timestamp = u64;
+
+pub const DEFAULT_APPROVAL_QUORUM: usize = 1;
+pub const DEFAULT_TIMELOCK: Timestamp = 24 * 60 * 60;
+/// Time left to approve and execute a proposal, on top of the timelock
+pub const PROPOSAL_LIFETIME: Timestamp = 7 * 24 * 60 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum ProposalAction<M: ManagedTypeApi> {
+    UnpauseGasBurner(ManagedAddress<M>),
+    UnpauseAllGasBurners,
+    UnpauseGasBurnerFlag(ManagedAddress<M>, PauseFlag),
+    AddGasBurner(ManagedAddress<M>),
+    RemoveGasBurner(ManagedAddress<M>),
+    AddSigner(ManagedAddress<M>, ManagedAddress<M>),
+    RemoveSigner(ManagedAddress<M>, u32),
+    RotateSigner(ManagedAddress<M>, u32, ManagedAddress<M>),
+    SetSignatureThreshold(ManagedAddress<M>, usize),
+    SetChainId(ManagedAddress<M>, ManagedBuffer<M>),
+    SetWorkVerificationMode(ManagedAddress<M>, WorkVerificationMode),
+    SetPowConfig(ManagedAddress<M>, u32, u64),
+    SetMintMode(ManagedAddress<M>, MintMode),
+    UpgradeGasBurner(GasBurnerUpgrade<M>),
+    TransferGasBurnerOwnership(ManagedAddress<M>, ManagedAddress<M>),
+    IssueGasBurnerToken(GasBurnerTokenIssue<M>),
+    CallGasBurner(
+        ManagedAddress<M>,
+        ManagedBuffer<M>,
+        ManagedVec<M, ManagedBuffer<M>>,
+    ),
+    GrantRole(ManagedAddress<M>, AdminRole),
+    RevokeRole(ManagedAddress<M>, AdminRole),
+    SetApprovalQuorum(usize),
+    SetTimelock(Timestamp),
+    SetDevRewardsSplit(DevRewardsSplit<M>),
+    WithdrawReserve(BigUint<M>, ManagedAddress<M>),
+    RecoverFunds(
+        EgldOrEsdtTokenIdentifier<M>,
+        u64,
+        BigUint<M>,
+        ManagedAddress<M>,
+    ),
+    RecoverGasBurnerFunds(
+        ManagedAddress<M>,
+        EgldOrEsdtTokenIdentifier<M>,
+        u64,
+        BigUint<M>,
+        ManagedAddress<M>,
+    ),
+}
+
+impl<M: ManagedTypeApi> ProposalAction<M> {
+    /// Async actions are only done once their callback confirms it
+    pub fn is_async(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::UpgradeGasBurner(_) | ProposalAction::IssueGasBurnerToken(_)
+        )
+    }
+
+    pub fn required_role(&self) -> AdminRole {
+        match self {
+            ProposalAction::UnpauseGasBurner(_)
+            | ProposalAction::UnpauseAllGasBurners
+            | ProposalAction::UnpauseGasBurnerFlag(..) => AdminRole::Pauser,
+            ProposalAction::SetDevRewardsSplit(_)
+            | ProposalAction::IssueGasBurnerToken(_)
+            | ProposalAction::WithdrawReserve(..)
+            | ProposalAction::RecoverFunds(..)
+            | ProposalAction::RecoverGasBurnerFunds(..) => AdminRole::Treasurer,
+            ProposalAction::GrantRole(_, role) | ProposalAction::RevokeRole(_, role) => *role,
+            _ => AdminRole::Configurator,
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub action: ProposalAction<M>,
+    pub proposer: ManagedAddress<M>,
+    pub executable_after: Option<Timestamp>,
+    pub expires_at: Timestamp,
+}
+
+#[multiversx_sc::module]
+pub trait GovernanceModule:
+    crate::roles::RolesModule
+    + crate::reserve::ReserveModule
+    + crate::gas_burner::GasBurnerModule
+    + crate::dev_rewards_split::DevRewardsSplitModule
+    + crate::recovery::RecoveryModule
+{
+    /// Only admins with the role required by the action may propose and approve it
+    /// The proposer's approval is counted automatically
+    #[endpoint]
+    fn propose(&self, action: ProposalAction<Self::Api>) -> ProposalId {
+        self.require_role(action.required_role());
+
+        let caller = self.blockchain().get_caller();
+        let proposal_id = self.last_proposal_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.proposal(proposal_id).set(Proposal {
+            action,
+            proposer: caller.clone(),
+            executable_after: None,
+            expires_at: current_timestamp + self.timelock().get() + PROPOSAL_LIFETIME,
+        });
+        self.approve_proposal_common(proposal_id, caller);
+
+        proposal_id
+    }
+
+    #[endpoint]
+    fn approve(&self, proposal_id: ProposalId) {
+        let proposal = self.get_existing_proposal(proposal_id);
+        self.require_role(proposal.action.required_role());
+        self.require_proposal_not_expired(&proposal);
+
+        let caller = self.blockchain().get_caller();
+        self.approve_proposal_common(proposal_id, caller);
+    }
+
+    /// Only the proposer may cancel, so no single admin can veto an approved proposal
+    #[endpoint(cancelProposal)]
+    fn cancel_proposal(&self, proposal_id: ProposalId) {
+        let proposal = self.get_existing_proposal(proposal_id);
+        require!(
+            proposal.proposer == self.blockchain().get_caller(),
+            "Only the proposer may cancel"
+        );
+        self.require_proposal_not_in_progress(proposal_id);
+
+        self.clear_proposal(proposal_id);
+    }
+
+    /// Anyone may execute a proposal once it has enough approvals and its timelock expired
+    /// Approvals are counted again against the current quorum,
+    /// ignoring approvers who no longer hold the required role
+    /// Async actions keep the proposal in progress until their callback,
+    /// which removes it on success and makes it executable again on failure
+    #[endpoint(executeProposal)]
+    fn execute_proposal(&self, proposal_id: ProposalId) {
+        let proposal = self.get_existing_proposal(proposal_id);
+        self.require_proposal_not_in_progress(proposal_id);
+        self.require_proposal_not_expired(&proposal);
+        let executable_after = match proposal.executable_after {
+            Some(timestamp) => timestamp,
+            None => sc_panic!("Not enough approvals"),
+        };
+        require!(
+            self.blockchain().get_block_timestamp() >= executable_after,
+            "Timelock not expired"
+        );
+        require!(
+            self.count_valid_approvals(proposal_id, proposal.action.required_role())
+                >= self.approval_quorum().get(),
+            "Not enough approvals"
+        );
+
+        if proposal.action.is_async() {
+            self.proposal_in_progress(proposal_id).set(true);
+        } else {
+            self.clear_proposal(proposal_id);
+        }
+        self.execute_action(proposal_id, proposal.action);
+        self.proposal_executed_event(proposal_id);
+    }
+
+    fn approve_proposal_common(&self, proposal_id: ProposalId, approver: ManagedAddress) {
+        let is_new_approval = self.proposal_approvers(proposal_id).insert(approver);
+        require!(is_new_approval, "Already approved");
+
+        self.proposal(proposal_id).update(|proposal| {
+            if proposal.executable_after.is_none()
+                && self.count_valid_approvals(proposal_id, proposal.action.required_role())
+                    >= self.approval_quorum().get()
+            {
+                let current_timestamp = self.blockchain().get_block_timestamp();
+                proposal.executable_after = Some(current_timestamp + self.timelock().get());
+            }
+        });
+    }
+
+    fn execute_action(&self, proposal_id: ProposalId, action: ProposalAction<Self::Api>) {
+        match action {
+            ProposalAction::UnpauseGasBurner(gas_burner) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .unpause_endpoint()
+                    .sync_call();
+            }
+            ProposalAction::UnpauseAllGasBurners => {
+                for gas_burner in self.gas_burners().iter() {
+                    self.tx()
+                        .to(&gas_burner)
+                        .typed(GasBurnerProxy)
+                        .unpause_endpoint()
+                        .sync_call();
+                }
+            }
+            ProposalAction::UnpauseGasBurnerFlag(gas_burner, flag) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .unpause_flag(flag)
+                    .sync_call();
+            }
+            ProposalAction::AddGasBurner(gas_burner) => {
+                self.require_valid_gas_burner_address(&gas_burner);
+                require!(
+                    self.gas_burners().insert(gas_burner),
+                    "Gas burner already registered"
+                );
+            }
+            ProposalAction::RemoveGasBurner(gas_burner) => {
+                require!(
+                    self.gas_burners().swap_remove(&gas_burner),
+                    "Unknown gas burner"
+                );
+            }
+            ProposalAction::AddSigner(gas_burner, signer) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .add_signer(signer)
+                    .sync_call();
+            }
+            ProposalAction::RemoveSigner(gas_burner, key_id) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .remove_signer(key_id)
+                    .sync_call();
+            }
+            ProposalAction::RotateSigner(gas_burner, key_id, new_signer) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .rotate_signer(key_id, new_signer)
+                    .sync_call();
+            }
+            ProposalAction::SetSignatureThreshold(gas_burner, threshold) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .set_signature_threshold(threshold)
+                    .sync_call();
+            }
+            ProposalAction::SetChainId(gas_burner, chain_id) => {
+                self.require_registered_gas_burner(&gas_burner);
+                self.tx()
+                    .to(&gas_burner)
+                    .typed(GasBurnerProxy)
+                    .set_chain_id(chain_id)
+                    .sync_call();
+            }
+            ProposalAction::SetWorkVerificationMode(gas_burner, mode) => {
+                self.set_gas_burner_work_verification_mode(gas_burner, mode);
+            }
+            ProposalAction::SetPowConfig(
+                gas_burner,
+                initial_difficulty,
+                target_submissions_per_week,
+            ) => {
+                self.set_gas_burner_pow_config(
+                    gas_burner,
+                    initial_difficulty,
+                    target_submissions_per_week,
+                );
+            }
+            ProposalAction::SetMintMode(gas_burner, mode) => {
+                self.set_gas_burner_mint_mode(gas_burner, mode);
+            }
+            ProposalAction::UpgradeGasBurner(upgrade) => {
+                let callback = self.callbacks().async_proposal_callback(proposal_id);
+                self.upgrade_gas_burner(upgrade, callback);
+            }
+            ProposalAction::TransferGasBurnerOwnership(gas_burner, new_owner) => {
+                self.transfer_gas_burner_ownership(gas_burner, new_owner);
+            }
+            ProposalAction::IssueGasBurnerToken(issue) => {
+                let callback = self
+                    .callbacks()
+                    .issue_gas_burner_token_callback(proposal_id, issue.issue_cost.clone());
+                self.issue_gas_burner_token(issue, callback);
+            }
+            ProposalAction::CallGasBurner(gas_burner, endpoint_name, arguments) => {
+                self.call_gas_burner(gas_burner, endpoint_name, arguments);
+            }
+            ProposalAction::GrantRole(admin, role) => {
+                let _ = self.role_members(role).insert(admin);
+            }
+            ProposalAction::RevokeRole(admin, role) => {
+                let mut role_members_mapper = self.role_members(role);
+                require!(role_members_mapper.swap_remove(&admin), "Admin not found");
+                require!(
+                    role_members_mapper.len() >= self.approval_quorum().get(),
+                    "Not enough admins left for the quorum"
+                );
+            }
+            ProposalAction::SetApprovalQuorum(quorum) => {
+                self.set_approval_quorum(quorum);
+            }
+            ProposalAction::SetTimelock(timelock) => {
+                self.timelock().set(timelock);
+            }
+            ProposalAction::SetDevRewardsSplit(split) => {
+                self.set_dev_rewards_split(split);
+            }
+            ProposalAction::WithdrawReserve(amount, destination) => {
+                self.withdraw_reserve(amount, destination);
+            }
+            ProposalAction::RecoverFunds(token_id, token_nonce, amount, destination) => {
+                self.recover_funds(token_id, token_nonce, amount, destination);
+            }
+            ProposalAction::RecoverGasBurnerFunds(
+                gas_burner,
+                token_id,
+                token_nonce,
+                amount,
+                destination,
+            ) => {
+                self.recover_gas_burner_funds(
+                    gas_burner,
+                    token_id,
+                    token_nonce,
+                    amount,
+                    destination,
+                );
+            }
+        }
+    }
+
+    #[callback]
+    fn async_proposal_callback(
+        &self,
+        proposal_id: ProposalId,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        self.finish_async_proposal(proposal_id, result.is_ok());
+    }
+
+    #[callback]
+    fn issue_gas_burner_token_callback(
+        &self,
+        proposal_id: ProposalId,
+        issue_cost: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if result.is_err() {
+            self.reserve().update(|reserve| *reserve += issue_cost);
+        }
+        self.finish_async_proposal(proposal_id, result.is_ok());
+    }
+
+    fn finish_async_proposal(&self, proposal_id: ProposalId, is_success: bool) {
+        if is_success {
+            self.clear_proposal(proposal_id);
+            self.proposal_executed_event(proposal_id);
+        } else {
+            self.proposal_in_progress(proposal_id).clear();
+            self.proposal_execution_failed_event(proposal_id);
+        }
+    }
+
+    /// The quorum must be reachable for every role
+    fn set_approval_quorum(&self, quorum: usize) {
+        require!(quorum > 0, "Invalid quorum");
+        for role in crate::roles::ALL_ADMIN_ROLES {
+            require!(
+                self.role_members(role).len() >= quorum,
+                "Not enough admins for the quorum"
+            );
+        }
+
+        self.approval_quorum().set(quorum);
+    }
+
+    fn count_valid_approvals(&self, proposal_id: ProposalId, role: AdminRole) -> usize {
+        let role_members_mapper = self.role_members(role);
+        self.proposal_approvers(proposal_id)
+            .iter()
+            .filter(|approver| role_members_mapper.contains(approver))
+            .count()
+    }
+
+    fn require_proposal_not_in_progress(&self, proposal_id: ProposalId) {
+        require!(
+            !self.proposal_in_progress(proposal_id).get(),
+            "Proposal in progress"
+        );
+    }
+
+    fn require_proposal_not_expired(&self, proposal: &Proposal<Self::Api>) {
+        require!(
+            self.blockchain().get_block_timestamp() < proposal.expires_at,
+            "Proposal expired"
+        );
+    }
+
+    fn get_existing_proposal(&self, proposal_id: ProposalId) -> Proposal<Self::Api> {
+        let proposal_mapper = self.proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), "Unknown proposal");
+
+        proposal_mapper.get()
+    }
+
+    fn clear_proposal(&self, proposal_id: ProposalId) {
+        self.proposal(proposal_id).clear();
+        self.proposal_approvers(proposal_id).clear();
+        self.proposal_in_progress(proposal_id).clear();
+    }
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(&self, #[indexed] proposal_id: ProposalId);
+
+    #[event("proposalExecutionFailed")]
+    fn proposal_execution_failed_event(&self, #[indexed] proposal_id: ProposalId);
+
+    #[view(getProposal)]
+    #[storage_mapper("proposal")]
+    fn proposal(&self, proposal_id: ProposalId) -> SingleValueMapper<Proposal<Self::Api>>;
+
+    #[view(getProposalApprovers)]
+    #[storage_mapper("proposalApprovers")]
+    fn proposal_approvers(&self, proposal_id: ProposalId) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Set while an async action awaits its callback
+    #[view(isProposalInProgress)]
+    #[storage_mapper("proposalInProgress")]
+    fn proposal_in_progress(&self, proposal_id: ProposalId) -> SingleValueMapper<bool>;
+
+    #[view(getLastProposalId)]
+    #[storage_mapper("lastProposalId")]
+    fn last_proposal_id(&self) -> SingleValueMapper<ProposalId>;
+
+    #[view(getApprovalQuorum)]
+    #[storage_mapper("approvalQuorum")]
+    fn approval_quorum(&self) -> SingleValueMapper<usize>;
+
+    /// Delay in seconds between a proposal reaching the quorum and it becoming executable
+    #[view(getTimelock)]
+    #[storage_mapper("timelock")]
+    fn timelock(&self) -> SingleValueMapper<Timestamp>;
+}
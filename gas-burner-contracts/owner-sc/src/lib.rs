@@ -2,78 +2,70 @@
 
 multiversx_sc::imports!();
 
-mod pausable_sc_proxy {
-    multiversx_sc::imports!();
-
-    #[multiversx_sc::proxy]
-    pub trait PausableScProxy {
-        #[endpoint]
-        fn pause(&self);
-
-        #[endpoint]
-        fn unpause(&self);
-    }
-}
+pub mod dev_rewards_split;
+pub mod gas_burner;
+pub mod gas_burner_proxy;
+pub mod governance;
+pub mod migration;
+pub mod recovery;
+pub mod reserve;
+pub mod roles;
 
 #[multiversx_sc::contract]
-pub trait OwnerSc {
+pub trait OwnerSc:
+    roles::RolesModule
+    + gas_burner::GasBurnerModule
+    + governance::GovernanceModule
+    + reserve::ReserveModule
+    + dev_rewards_split::DevRewardsSplitModule
+    + recovery::RecoveryModule
+    + migration::MigrationModule
+{
+    /// The deployer starts with every admin role
+    /// Further admins are added through proposals
     #[init]
-    fn init(&self) {}
-
-    #[upgrade]
-    fn upgrade(&self) {}
-
-    #[only_owner]
-    #[endpoint(setGasBurnerAddress)]
-    fn set_gas_burner_address(&self, gas_burner: ManagedAddress) {
-        require!(
-            !gas_burner.is_zero() && self.blockchain().is_smart_contract(&gas_burner),
-            "Invalid SC address"
-        );
-
-        self.gas_burner().set(gas_burner);
-    }
-
-    #[only_owner]
-    #[endpoint(pauseGasBurner)]
-    fn pause_gas_burner(&self) {
-        let gas_burner = self.gas_burner().get();
-        self.gas_burner_proxy(gas_burner)
-            .pause()
-            .execute_on_dest_context()
+    fn init(&self) {
+        let caller = self.blockchain().get_caller();
+        self.grant_all_roles(&caller);
+        self.approval_quorum()
+            .set(governance::DEFAULT_APPROVAL_QUORUM);
+        self.timelock().set(governance::DEFAULT_TIMELOCK);
+        self.storage_version()
+            .set(migration::CURRENT_STORAGE_VERSION);
     }
 
-    #[only_owner]
-    #[endpoint(unpauseGasBurner)]
-    fn unpause_gas_burner(&self) {
-        let gas_burner = self.gas_burner().get();
-        self.gas_burner_proxy(gas_burner)
-            .unpause()
-            .execute_on_dest_context()
+    #[upgrade]
+    fn upgrade(&self) {
+        self.run_migrations();
     }
 
+    /// Only the rewards claimed for the calling gas burner are split,
+    /// the rest of the balance is left untouched
+    /// Returns the prize pool share, which is forwarded to the gas burner
     #[endpoint(claimDevRewards)]
     fn claim_dev_rewards(&self) -> BigUint {
         let caller = self.blockchain().get_caller();
-        let gas_burner = self.gas_burner().get();
-        require!(
-            caller == gas_burner,
-            "Only the gas burner SC may call this endpoint"
-        );
-
-        self.send().claim_developer_rewards(gas_burner).sync_call();
+        self.require_registered_gas_burner(&caller);
 
         let own_sc_address = self.blockchain().get_sc_address();
+        let egld_balance_before = self.blockchain().get_balance(&own_sc_address);
+        self.send()
+            .claim_developer_rewards(caller.clone())
+            .sync_call();
         let egld_balance_after = self.blockchain().get_balance(&own_sc_address);
+
+        let dev_rewards = egld_balance_after - egld_balance_before;
+        self.dev_rewards_claimed(&caller)
+            .update(|claimed| *claimed += &dev_rewards);
+        let prize_pool_amount = self.split_dev_rewards(caller.clone(), dev_rewards);
         self.send()
-            .direct_non_zero_egld(&caller, &egld_balance_after);
+            .direct_non_zero_egld(&caller, &prize_pool_amount);
 
-        egld_balance_after
+        prize_pool_amount
     }
 
-    #[storage_mapper("gasBurner")]
-    fn gas_burner(&self) -> SingleValueMapper<ManagedAddress>;
-
-    #[proxy]
-    fn gas_burner_proxy(&self, sc_address: ManagedAddress) -> pausable_sc_proxy::Proxy<Self::Api>;
+    /// Cumulative amount claimed for the gas burner, before the split
+    #[view(getDevRewardsClaimed)]
+    #[storage_mapper("devRewardsClaimed")]
+    fn dev_rewards_claimed(&self, gas_burner: &ManagedAddress) -> SingleValueMapper<BigUint>;
 }
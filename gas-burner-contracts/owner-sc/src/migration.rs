@@ -0,0 +1,58 @@
+multiversx_sc::imports!();
+
+pub type StorageVersion = u32;
+
+/// Version of deployments from before storage versioning was introduced
+pub const LEGACY_STORAGE_VERSION: StorageVersion = 1;
+pub const CURRENT_STORAGE_VERSION: StorageVersion = 2;
+
+/// Storage layout changes are migrated one version at a time, on upgrade
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::roles::RolesModule
+    + crate::reserve::ReserveModule
+    + crate::gas_burner::GasBurnerModule
+    + crate::governance::GovernanceModule
+    + crate::dev_rewards_split::DevRewardsSplitModule
+    + crate::recovery::RecoveryModule
+{
+    fn run_migrations(&self) {
+        let storage_version_mapper = self.storage_version();
+        if storage_version_mapper.is_empty() {
+            storage_version_mapper.set(LEGACY_STORAGE_VERSION);
+        }
+
+        loop {
+            let storage_version = storage_version_mapper.get();
+            match storage_version {
+                CURRENT_STORAGE_VERSION => return,
+                LEGACY_STORAGE_VERSION => self.migrate_to_v2(),
+                _ => sc_panic!("Unknown storage version"),
+            }
+
+            storage_version_mapper.set(storage_version + 1);
+        }
+    }
+
+    /// Previous versions were fully controlled by the owner, who keeps every role,
+    /// and managed a single gas burner, which becomes the first registered one
+    fn migrate_to_v2(&self) {
+        if self.approval_quorum().is_empty() {
+            let owner = self.blockchain().get_owner_address();
+            self.grant_all_roles(&owner);
+            self.approval_quorum()
+                .set(crate::governance::DEFAULT_APPROVAL_QUORUM);
+            self.timelock().set(crate::governance::DEFAULT_TIMELOCK);
+        }
+
+        let legacy_gas_burner_mapper = self.legacy_gas_burner();
+        if !legacy_gas_burner_mapper.is_empty() {
+            let _ = self.gas_burners().insert(legacy_gas_burner_mapper.get());
+            legacy_gas_burner_mapper.clear();
+        }
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<StorageVersion>;
+}
@@ -0,0 +1,74 @@
+use crate::gas_burner_proxy::GasBurnerProxy;
+
+multiversx_sc::imports!();
+
+/// Recoveries go through a proposal
+#[multiversx_sc::module]
+pub trait RecoveryModule:
+    crate::roles::RolesModule + crate::reserve::ReserveModule + crate::gas_burner::GasBurnerModule
+{
+    /// Dev rewards are forwarded in the same transaction they are claimed in,
+    /// so only the reserve is kept back
+    fn recover_funds(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+        destination: ManagedAddress,
+    ) {
+        require!(amount > 0, "Invalid amount");
+        require!(
+            amount <= self.get_recoverable_balance(token_id.clone(), token_nonce),
+            "Amount exceeds the recoverable balance"
+        );
+
+        self.send()
+            .direct(&destination, &token_id, token_nonce, &amount);
+        self.funds_recovered_event(&token_id, token_nonce, &destination, &amount);
+    }
+
+    fn recover_gas_burner_funds(
+        &self,
+        gas_burner: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+        destination: ManagedAddress,
+    ) {
+        self.require_registered_gas_burner(&gas_burner);
+
+        self.tx()
+            .to(&gas_burner)
+            .typed(GasBurnerProxy)
+            .recover_funds(token_id, token_nonce, amount, destination)
+            .sync_call();
+    }
+
+    #[view(getRecoverableBalance)]
+    fn get_recoverable_balance(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+    ) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(&token_id, token_nonce);
+        if !token_id.is_egld() {
+            return balance;
+        }
+
+        let reserve = self.reserve().get();
+        if balance > reserve {
+            balance - reserve
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    #[event("fundsRecovered")]
+    fn funds_recovered_event(
+        &self,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] destination: &ManagedAddress,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,30 @@
+multiversx_sc::imports!();
+
+/// EGLD kept by the owner SC for its own purposes, never forwarded as dev rewards
+/// Anyone may deposit, withdrawals go through a proposal
+#[multiversx_sc::module]
+pub trait ReserveModule: crate::roles::RolesModule {
+    #[payable("EGLD")]
+    #[endpoint(depositReserve)]
+    fn deposit_reserve(&self) {
+        let payment_amount = self.call_value().egld_value().clone_value();
+        require!(payment_amount > 0, "No EGLD sent");
+
+        self.reserve().update(|reserve| *reserve += payment_amount);
+    }
+
+    fn withdraw_reserve(&self, amount: BigUint, destination: ManagedAddress) {
+        require!(amount > 0, "Invalid amount");
+
+        self.reserve().update(|reserve| {
+            require!(*reserve >= amount, "Not enough EGLD in the reserve");
+            *reserve -= &amount;
+        });
+
+        self.send().direct_egld(&destination, &amount);
+    }
+
+    #[view(getReserve)]
+    #[storage_mapper("reserve")]
+    fn reserve(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,37 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum AdminRole {
+    Pauser,
+    Configurator,
+    Treasurer,
+}
+
+pub const ALL_ADMIN_ROLES: [AdminRole; 3] = [
+    AdminRole::Pauser,
+    AdminRole::Configurator,
+    AdminRole::Treasurer,
+];
+
+#[multiversx_sc::module]
+pub trait RolesModule {
+    fn require_role(&self, role: AdminRole) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(role).contains(&caller),
+            "Caller does not have the required role"
+        );
+    }
+
+    fn grant_all_roles(&self, admin: &ManagedAddress) {
+        for role in ALL_ADMIN_ROLES {
+            let _ = self.role_members(role).insert(admin.clone());
+        }
+    }
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: AdminRole) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            4
-// Async Callback (empty):               1
-// Total number of exported functions:   7
+// Endpoints:                           24
+// Async Callback:                       1
+// Total number of exported functions:  27
 
 #![no_std]
 
@@ -20,11 +20,31 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
-        setGasBurnerAddress => set_gas_burner_address
-        pauseGasBurner => pause_gas_burner
-        unpauseGasBurner => unpause_gas_burner
         claimDevRewards => claim_dev_rewards
+        getDevRewardsClaimed => dev_rewards_claimed
+        getRoleMembers => role_members
+        pauseGasBurner => pause_gas_burner
+        pauseAllGasBurners => pause_all_gas_burners
+        pauseGasBurnerFlag => pause_gas_burner_flag
+        getGasBurners => gas_burners
+        propose => propose
+        approve => approve
+        cancelProposal => cancel_proposal
+        executeProposal => execute_proposal
+        getProposal => proposal
+        getProposalApprovers => proposal_approvers
+        isProposalInProgress => proposal_in_progress
+        getLastProposalId => last_proposal_id
+        getApprovalQuorum => approval_quorum
+        getTimelock => timelock
+        depositReserve => deposit_reserve
+        getReserve => reserve
+        getDevRewardsSplit => dev_rewards_split
+        getDevRewardsClaim => dev_rewards_claim
+        getLastDevRewardsClaimId => last_dev_rewards_claim_id
+        getRecoverableBalance => get_recoverable_balance
+        getStorageVersion => storage_version
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { owner_sc }